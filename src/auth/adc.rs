@@ -0,0 +1,197 @@
+//! Application Default Credentials, see <https://cloud.google.com/docs/authentication/application-default-credentials>.
+
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use super::{AccessToken, AuthorizedUser, Credentials, ServiceAccountCredentials, TokenResponse};
+use crate::error::{deserialize, Error, Result};
+use crate::transport::{Method, Request, Transport};
+
+/// Points at a service-account or authorized-user JSON file.
+pub const CREDENTIALS_ENV: &str = "GOOGLE_APPLICATION_CREDENTIALS";
+/// Overrides the gcloud configuration directory.
+pub const CLOUDSDK_CONFIG_ENV: &str = "CLOUDSDK_CONFIG";
+/// Overrides the host (and port) of the GCE metadata server.
+pub const METADATA_HOST_ENV: &str = "GCE_METADATA_HOST";
+
+const DEFAULT_METADATA_HOST: &str = "metadata.google.internal";
+const WELL_KNOWN_FILE: &str = "application_default_credentials.json";
+const METADATA_PROBE_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Resolves credentials in ADC order:
+/// the `GOOGLE_APPLICATION_CREDENTIALS` file, gcloud's well-known file, then the GCE metadata server.
+/// `transport` is used to probe the metadata server.
+pub fn find_credentials(transport: &dyn Transport) -> Result<Arc<dyn Credentials>> {
+    discover(transport, &|name| std::env::var_os(name))
+}
+
+/// `find_credentials` with the environment variables looked up by `env`.
+fn discover(transport: &dyn Transport, env: &dyn Fn(&str) -> Option<OsString>) -> Result<Arc<dyn Credentials>> {
+    if let Some(path) = env(CREDENTIALS_ENV).filter(|path| !path.is_empty()) {
+        return from_file(path);
+    }
+
+    if let Some(path) = well_known_file(env).filter(|path| path.is_file()) {
+        return from_file(path);
+    }
+
+    let metadata = MetadataCredentials::from_env(env);
+    if metadata.is_available(transport) {
+        return Ok(Arc::new(metadata));
+    }
+
+    Err(Error::Credentials(format!(
+        "couldn't find default credentials: set {}, run `gcloud auth application-default login` or run on GCE",
+        CREDENTIALS_ENV
+    )))
+}
+
+/// Loads a service-account or authorized-user JSON file.
+pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Arc<dyn Credentials>> {
+    #[derive(Deserialize)]
+    struct Kind {
+        #[serde(rename = "type")]
+        kind: String,
+    }
+
+    let path = path.as_ref();
+    let data = std::fs::read(path)
+        .map_err(|e| Error::Credentials(format!("couldn't read {}: {}", path.display(), e)))?;
+
+    match deserialize::<Kind>(&data)?.kind.as_str() {
+        "service_account" => Ok(Arc::new(ServiceAccountCredentials::new(deserialize(&data)?)?)),
+        "authorized_user" => Ok(Arc::new(deserialize::<AuthorizedUser>(&data)?)),
+        kind => Err(Error::Credentials(format!(
+            "unsupported credentials type `{}` in {}",
+            kind,
+            path.display()
+        ))),
+    }
+}
+
+/// The gcloud `application_default_credentials.json` location.
+fn well_known_file(env: &dyn Fn(&str) -> Option<OsString>) -> Option<PathBuf> {
+    let config = match env(CLOUDSDK_CONFIG_ENV) {
+        Some(dir) => PathBuf::from(dir),
+        None if cfg!(windows) => PathBuf::from(env("APPDATA")?).join("gcloud"),
+        None => PathBuf::from(env("HOME")?).join(".config").join("gcloud"),
+    };
+
+    Some(config.join(WELL_KNOWN_FILE))
+}
+
+/// Credentials of the service account attached to a GCE VM (or GKE, Cloud Run, ...).
+pub struct MetadataCredentials {
+    host: String,
+}
+
+impl MetadataCredentials {
+    /// Talks to `GCE_METADATA_HOST`, or `metadata.google.internal` if unset.
+    pub fn new() -> Self {
+        Self::from_env(&|name| std::env::var_os(name))
+    }
+
+    /// `new` with the environment variables looked up by `env`.
+    fn from_env(env: &dyn Fn(&str) -> Option<OsString>) -> Self {
+        let host = env(METADATA_HOST_ENV)
+            .and_then(|host| host.into_string().ok())
+            .filter(|host| !host.is_empty())
+            .unwrap_or_else(|| DEFAULT_METADATA_HOST.to_string());
+
+        Self { host }
+    }
+
+    /// Whether the metadata server answers.
+    pub fn is_available(&self, transport: &dyn Transport) -> bool {
+        let request = Request::new(Method::Get, format!("http://{}/computeMetadata/v1/", self.host))
+            .header("Metadata-Flavor", "Google")
+            .timeout(METADATA_PROBE_TIMEOUT);
+
+        transport.send(&request).is_ok_and(|response| response.status == 200)
+    }
+}
+
+impl Default for MetadataCredentials {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Credentials for MetadataCredentials {
+    fn fetch_token(&self, transport: &dyn Transport, scopes: &[&str]) -> Result<AccessToken> {
+        let query = form_urlencoded::Serializer::new(String::new())
+            .append_pair("scopes", &scopes.join(","))
+            .finish();
+        let url = format!(
+            "http://{}/computeMetadata/v1/instance/service-accounts/default/token?{}",
+            self.host, query
+        );
+        let request = Request::new(Method::Get, url).header("Metadata-Flavor", "Google");
+        let response = transport.send(&request)?;
+
+        match response.status {
+            200 => deserialize::<TokenResponse>(&response.body).map(AccessToken::from),
+            status => Err(Error::from_response(status, &response.body)),
+        }
+    }
+
+    /// The default service account of the instance.
+    fn account(&self) -> Option<String> {
+        Some(format!("default@{}", self.host))
+    }
+}
+
+//<editor-fold desc="Tests">
+
+#[test]
+fn test_discovery_chain() {
+    let dir = std::env::temp_dir().join(format!("google_api_adc_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let user_file = dir.join("user.json");
+    std::fs::write(
+        &user_file,
+        r#"{"type":"authorized_user","client_id":"id","client_secret":"secret","refresh_token":"1//r"}"#,
+    )
+    .unwrap();
+    let (url, server) = crate::test_server::serve(vec![
+        (200, String::new()),
+        (200, r#"{"access_token":"ya29.metadata","expires_in":3599,"token_type":"Bearer"}"#.to_string()),
+    ]);
+
+    let transport = crate::transport::default_transport();
+    let host = url.trim_start_matches("http://");
+    let env = |vars: Vec<(&'static str, OsString)>| {
+        move |name: &str| vars.iter().find(|(var, _)| *var == name).map(|(_, value)| value.clone())
+    };
+
+    // 1. The explicit file wins.
+    let explicit = env(vec![(CREDENTIALS_ENV, user_file.clone().into()), (CLOUDSDK_CONFIG_ENV, dir.clone().into())]);
+    assert!(discover(&*transport, &explicit).is_ok());
+
+    // 2. The well-known file, here inside an empty gcloud config directory.
+    let gcloud = env(vec![(CLOUDSDK_CONFIG_ENV, dir.clone().into()), (METADATA_HOST_ENV, host.into())]);
+    std::fs::copy(&user_file, dir.join(WELL_KNOWN_FILE)).unwrap();
+    assert!(discover(&*transport, &gcloud).is_ok());
+
+    // 3. The metadata server.
+    std::fs::remove_file(dir.join(WELL_KNOWN_FILE)).unwrap();
+    let token = discover(&*transport, &gcloud)
+        .unwrap()
+        .fetch_token(&*transport, &[super::SPREADSHEETS_SCOPE])
+        .unwrap();
+    let received = server.join().unwrap();
+
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(token.token, "ya29.metadata");
+    assert_eq!(received[0].line, "GET /computeMetadata/v1/ HTTP/1.1");
+    assert_eq!(received[1].header("Metadata-Flavor"), Some("Google"));
+    assert!(received[1]
+        .line
+        .starts_with("GET /computeMetadata/v1/instance/service-accounts/default/token?scopes="));
+}
+//</editor-fold>
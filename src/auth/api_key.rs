@@ -0,0 +1,51 @@
+use std::fmt::{Debug, Formatter};
+
+/// An API key, granting read-only access to public spreadsheets.
+///
+/// The key never shows up in `Debug` output, and is scrubbed from error messages.
+#[derive(Clone)]
+pub struct ApiKey(String);
+
+impl ApiKey {
+    pub fn new<S: Into<String>>(key: S) -> Self {
+        Self(key.into())
+    }
+
+    /// Returns `url` with the key appended to its query string.
+    pub(crate) fn append_to(&self, url: &str) -> String {
+        let separator = if url.contains('?') { '&' } else { '?' };
+        let query = form_urlencoded::Serializer::new(String::new())
+            .append_pair("key", &self.0)
+            .finish();
+
+        format!("{}{}{}", url, separator, query)
+    }
+
+    /// Replaces every occurrence of the key in `text`.
+    pub(crate) fn redact(&self, text: &str) -> String {
+        if self.0.is_empty() {
+            return text.to_string();
+        }
+
+        text.replace(&self.0, "<redacted>")
+    }
+}
+
+impl Debug for ApiKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ApiKey(<redacted>)")
+    }
+}
+
+//<editor-fold desc="Tests">
+
+#[test]
+fn test_api_key() {
+    let key = ApiKey::new("AIzaSyTest");
+
+    assert_eq!(key.append_to("https://host/v4/spreadsheets/id"), "https://host/v4/spreadsheets/id?key=AIzaSyTest");
+    assert_eq!(key.append_to("https://host/id?fields=a"), "https://host/id?fields=a&key=AIzaSyTest");
+    assert_eq!(format!("{:?}", key), "ApiKey(<redacted>)");
+    assert_eq!(key.redact("/id?key=AIzaSyTest was not found"), "/id?key=<redacted> was not found");
+}
+//</editor-fold>
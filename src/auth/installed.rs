@@ -0,0 +1,569 @@
+use std::io::{BufRead, BufReader, ErrorKind, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::{fingerprint, request_token, AccessToken, Credentials};
+use crate::endpoint::DEFAULT_TOKEN_URL;
+use crate::error::{deserialize, ApiError, Error, Result};
+use crate::transport::Transport;
+
+const DEFAULT_AUTH_URI: &str = "https://accounts.google.com/o/oauth2/auth";
+/// How long the user has to consent before the flow gives up.
+const DEFAULT_CONSENT_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+/// How long a connection to the loopback redirect may take to send its request line.
+const LOOPBACK_READ_TIMEOUT: Duration = Duration::from_secs(10);
+/// How often the loopback listener checks for a connection.
+const ACCEPT_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// The `installed` section of an OAuth client secret file, as downloaded from the Cloud console.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ClientSecret {
+    pub client_id: String,
+    pub client_secret: String,
+    #[serde(default = "default_auth_uri")]
+    pub auth_uri: String,
+    #[serde(default = "default_token_uri")]
+    pub token_uri: String,
+}
+
+#[derive(Deserialize)]
+struct ClientSecretFile {
+    installed: ClientSecret,
+}
+
+impl ClientSecret {
+    /// Reads a client secret file (`{"installed": {...}}`) from disk.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let data = read_file(path.as_ref())?;
+
+        deserialize::<ClientSecretFile>(&data).map(|file| file.installed)
+    }
+}
+
+impl std::fmt::Debug for ClientSecret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClientSecret")
+            .field("client_id", &self.client_id)
+            .field("auth_uri", &self.auth_uri)
+            .field("token_uri", &self.token_uri)
+            .finish()
+    }
+}
+
+/// A user who granted access to an OAuth client, in gcloud's `authorized_user` file format.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct AuthorizedUser {
+    /// Always `authorized_user`.
+    #[serde(rename = "type")]
+    pub user_type: String,
+    pub client_id: String,
+    pub client_secret: String,
+    pub refresh_token: String,
+    #[serde(default = "default_token_uri")]
+    pub token_uri: String,
+}
+
+impl AuthorizedUser {
+    /// Reads an `authorized_user` file from disk.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        deserialize(&read_file(path.as_ref())?)
+    }
+
+    /// Writes the user to disk, readable by the current user only.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let data = serde_json::to_vec_pretty(self).map_err(|e| Error::Credentials(e.to_string()))?;
+
+        write_private_file(path, &data)
+            .map_err(|e| Error::Credentials(format!("couldn't write {}: {}", path.display(), e)))
+    }
+}
+
+impl std::fmt::Debug for AuthorizedUser {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuthorizedUser")
+            .field("client_id", &self.client_id)
+            .field("token_uri", &self.token_uri)
+            .finish()
+    }
+}
+
+impl Credentials for AuthorizedUser {
+    fn fetch_token(&self, transport: &dyn Transport, _scopes: &[&str]) -> Result<AccessToken> {
+        request_token(
+            transport,
+            &self.token_uri,
+            &[
+                ("grant_type", "refresh_token"),
+                ("client_id", &self.client_id),
+                ("client_secret", &self.client_secret),
+                ("refresh_token", &self.refresh_token),
+            ],
+        )
+        .map(AccessToken::from)
+    }
+
+    fn account(&self) -> Option<String> {
+        // Users of the same OAuth client only differ by their refresh token.
+        Some(format!("{} {}", self.client_id, fingerprint(&self.refresh_token)))
+    }
+}
+
+/// The OAuth2 authorization-code flow for installed applications, with PKCE and a loopback redirect.
+///
+/// The first token request asks the user for consent; the refresh token obtained then is reused
+/// (and persisted, see `persist_to`) for every following request.
+/// Token requests needing consent meanwhile wait for that one, up to the timeout, the others don't.
+pub struct InstalledFlow {
+    secret: ClientSecret,
+    path: Option<PathBuf>,
+    consent: Box<dyn Fn(&str) + Send + Sync>,
+    timeout: Duration,
+    user: Mutex<Option<AuthorizedUser>>,
+    /// Held while asking for consent, so the user is asked once.
+    consenting: Mutex<()>,
+}
+
+impl InstalledFlow {
+    /// A flow printing the consent URL to stderr.
+    pub fn new(secret: ClientSecret) -> Self {
+        Self {
+            secret,
+            path: None,
+            consent: Box::new(print_consent_url),
+            timeout: DEFAULT_CONSENT_TIMEOUT,
+            user: Mutex::new(None),
+            consenting: Mutex::new(()),
+        }
+    }
+
+    /// Stores the refresh token at `path`, reusing it if the file already exists.
+    pub fn persist_to<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        let path = path.into();
+        if let Ok(user) = AuthorizedUser::from_file(&path) {
+            if user.client_id == self.secret.client_id {
+                self.user = Mutex::new(Some(user));
+            }
+        }
+        self.path = Some(path);
+        self
+    }
+
+    /// Opens the consent URL in the default browser, printing it if that fails.
+    pub fn open_browser(self) -> Self {
+        self.with_consent_handler(open_consent_url)
+    }
+
+    /// Hands the consent URL to `handler` instead of printing it.
+    pub fn with_consent_handler<F: Fn(&str) + Send + Sync + 'static>(mut self, handler: F) -> Self {
+        self.consent = Box::new(handler);
+        self
+    }
+
+    /// How long to wait for the browser to come back to the loopback redirect, 5 minutes by default.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Runs the interactive authorization and returns the authorized user.
+    pub fn authorize(&self, transport: &dyn Transport, scopes: &[&str]) -> Result<AuthorizedUser> {
+        self.exchange(transport, scopes).map(|(user, _)| user)
+    }
+
+    fn exchange(&self, transport: &dyn Transport, scopes: &[&str]) -> Result<(AuthorizedUser, AccessToken)> {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .map_err(|e| Error::Credentials(format!("couldn't start the loopback listener: {}", e)))?;
+        let port = listener.local_addr().map_err(|e| Error::Credentials(e.to_string()))?.port();
+        let redirect_uri = format!("http://127.0.0.1:{}", port);
+
+        let verifier = random_string(64);
+        let challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()));
+        let state = random_string(32);
+        let scope = scopes.join(" ");
+        let query = form_urlencoded::Serializer::new(String::new())
+            .append_pair("response_type", "code")
+            .append_pair("client_id", &self.secret.client_id)
+            .append_pair("redirect_uri", &redirect_uri)
+            .append_pair("scope", &scope)
+            .append_pair("state", &state)
+            .append_pair("code_challenge", &challenge)
+            .append_pair("code_challenge_method", "S256")
+            .append_pair("access_type", "offline")
+            .append_pair("prompt", "consent")
+            .finish();
+
+        (self.consent)(&format!("{}?{}", self.secret.auth_uri, query));
+
+        let code = receive_code(&listener, &state, self.timeout)?;
+        let response = request_token(
+            transport,
+            &self.secret.token_uri,
+            &[
+                ("grant_type", "authorization_code"),
+                ("code", &code),
+                ("client_id", &self.secret.client_id),
+                ("client_secret", &self.secret.client_secret),
+                ("redirect_uri", &redirect_uri),
+                ("code_verifier", &verifier),
+            ],
+        )?;
+        let refresh_token = response
+            .refresh_token
+            .clone()
+            .ok_or_else(|| Error::Credentials("the token endpoint issued no refresh token".to_string()))?;
+        let user = AuthorizedUser {
+            user_type: "authorized_user".to_string(),
+            client_id: self.secret.client_id.clone(),
+            client_secret: self.secret.client_secret.clone(),
+            refresh_token,
+            token_uri: self.secret.token_uri.clone(),
+        };
+
+        Ok((user, response.into()))
+    }
+
+    fn current_user(&self) -> Option<AuthorizedUser> {
+        self.user.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    fn store(&self, user: &AuthorizedUser) -> Result<()> {
+        match &self.path {
+            Some(path) => user.save(path),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Credentials for InstalledFlow {
+    fn fetch_token(&self, transport: &dyn Transport, scopes: &[&str]) -> Result<AccessToken> {
+        let current = self.current_user();
+
+        if let Some(current) = current.as_ref() {
+            match current.fetch_token(transport, scopes) {
+                // The user revoked the grant, ask for consent again.
+                Err(Error::Auth { ref error, .. }) if error.status == "invalid_grant" => {}
+                result => return result,
+            }
+        }
+
+        // Only consenting is serialized, the user is read and replaced without waiting on the browser.
+        let _consenting = self.consenting.lock().unwrap_or_else(|e| e.into_inner());
+        let rejected = current.map(|user| user.refresh_token);
+        // Another request got consent while this one waited for it.
+        if let Some(latest) = self.current_user().filter(|latest| Some(&latest.refresh_token) != rejected.as_ref()) {
+            return latest.fetch_token(transport, scopes);
+        }
+
+        let (authorized, token) = self.exchange(transport, scopes)?;
+        self.store(&authorized)?;
+        *self.user.lock().unwrap_or_else(|e| e.into_inner()) = Some(authorized);
+
+        Ok(token)
+    }
+
+    /// The user who consented, or only the OAuth client until then.
+    fn account(&self) -> Option<String> {
+        match self.user.lock().unwrap_or_else(|e| e.into_inner()).as_ref() {
+            Some(user) => user.account(),
+            None => Some(self.secret.client_id.clone()),
+        }
+    }
+}
+
+/// Waits for the browser to hit the loopback redirect and extracts the authorization code.
+///
+/// Only a request carrying `state` is answered with a result, anything else, e.g. a stray
+/// request from another page or process, is ignored. Fails once `timeout` has elapsed.
+fn receive_code(listener: &TcpListener, state: &str, timeout: Duration) -> Result<String> {
+    let listener_error = |e: std::io::Error| Error::Credentials(format!("loopback listener failed: {}", e));
+    let deadline = Instant::now() + timeout;
+    listener.set_nonblocking(true).map_err(listener_error)?;
+
+    loop {
+        let stream = match listener.accept() {
+            Ok((stream, _)) => stream,
+            Err(e) if e.kind() == ErrorKind::WouldBlock => {
+                if Instant::now() >= deadline {
+                    return Err(Error::Credentials("timed out waiting for the authorization response".to_string()));
+                }
+                std::thread::sleep(ACCEPT_POLL_INTERVAL);
+                continue;
+            }
+            Err(e) => return Err(listener_error(e)),
+        };
+        // Accepted streams inherit the non-blocking mode on some platforms.
+        if stream.set_nonblocking(false).is_err() || stream.set_read_timeout(Some(LOOPBACK_READ_TIMEOUT)).is_err() {
+            continue;
+        }
+        let mut reader = BufReader::new(stream);
+        let mut line = String::new();
+        if reader.read_line(&mut line).is_err() {
+            continue;
+        }
+
+        // GET /?state=...&code=... HTTP/1.1
+        let target = line.split_whitespace().nth(1).unwrap_or_default();
+        let query = match target.split_once('?') {
+            Some((_, query)) => query,
+            // Browsers also ask for `/favicon.ico` and the like.
+            None => {
+                respond(reader.get_mut(), "404 Not Found", "");
+                continue;
+            }
+        };
+        let param = |name: &str| {
+            form_urlencoded::parse(query.as_bytes())
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.into_owned())
+        };
+        if param("state").as_deref() != Some(state) {
+            respond(reader.get_mut(), "400 Bad Request", "");
+            continue;
+        }
+
+        let (message, result) = match (param("code"), param("error")) {
+            (_, Some(error)) => (
+                "Authorization was denied, you may close this window.",
+                Err(Error::Auth {
+                    status: 401,
+                    error: ApiError {
+                        code: 401,
+                        message: "the user denied the authorization request".to_string(),
+                        status: error,
+                        details: vec![],
+                    },
+                }),
+            ),
+            (Some(code), None) => ("Authorization complete, you may close this window.", Ok(code)),
+            (None, None) => {
+                respond(reader.get_mut(), "400 Bad Request", "");
+                continue;
+            }
+        };
+        respond(reader.get_mut(), "200 OK", message);
+
+        return result;
+    }
+}
+
+/// Answers a request to the loopback redirect, ignoring failures since the browser may be gone already.
+fn respond(stream: &mut TcpStream, status: &str, message: &str) {
+    let _ = write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        message.len(),
+        message
+    );
+}
+
+fn print_consent_url(url: &str) {
+    eprintln!("Open the following URL in a browser to authorize this application:\n\n{}\n", url);
+}
+
+fn open_consent_url(url: &str) {
+    let opened = if cfg!(target_os = "macos") {
+        std::process::Command::new("open").arg(url).status()
+    } else if cfg!(target_os = "windows") {
+        std::process::Command::new("cmd").args(["/C", "start", "", url]).status()
+    } else {
+        std::process::Command::new("xdg-open").arg(url).status()
+    };
+
+    if !opened.is_ok_and(|status| status.success()) {
+        print_consent_url(url);
+    }
+}
+
+fn random_string(len: usize) -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(len)
+        .map(char::from)
+        .collect()
+}
+
+fn read_file(path: &Path) -> Result<Vec<u8>> {
+    std::fs::read(path).map_err(|e| Error::Credentials(format!("couldn't read {}: {}", path.display(), e)))
+}
+
+/// Writes `data` to `path`, creating the file with `0600` permissions on unix.
+pub(crate) fn write_private_file(path: &Path, data: &[u8]) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+
+    let mut file = options.open(path)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+    }
+
+    file.write_all(data)
+}
+
+fn default_auth_uri() -> String {
+    DEFAULT_AUTH_URI.to_string()
+}
+
+fn default_token_uri() -> String {
+    DEFAULT_TOKEN_URL.to_string()
+}
+
+//<editor-fold desc="Tests">
+
+/// Plays the browser: follows the consent URL straight to the loopback redirect.
+#[cfg(test)]
+fn consent(consent_url: &str) {
+    let query = consent_url.split_once('?').unwrap().1.to_string();
+    std::thread::spawn(move || {
+        let param = |name: &str| {
+            form_urlencoded::parse(query.as_bytes())
+                .find(|(key, _)| key == name)
+                .unwrap()
+                .1
+                .into_owned()
+        };
+        assert_eq!(param("code_challenge_method"), "S256");
+        let redirect = format!("{}/?state={}&code=4%2Fcode", param("redirect_uri"), param("state"));
+        let request = crate::transport::Request::new(crate::transport::Method::Get, redirect);
+        crate::transport::default_transport().send(&request).unwrap();
+    });
+}
+
+#[test]
+fn test_installed_flow() {
+    let responses = vec![
+        (200, r#"{"access_token":"ya29.first","refresh_token":"1//refresh","expires_in":3599}"#.to_string()),
+        (200, r#"{"access_token":"ya29.second","expires_in":3599}"#.to_string()),
+    ];
+    let (url, server) = crate::test_server::serve(responses);
+    let path = std::env::temp_dir().join(format!("google_api_installed_{}.json", std::process::id()));
+    let secret = ClientSecret {
+        client_id: "client.apps.googleusercontent.com".to_string(),
+        client_secret: "secret".to_string(),
+        auth_uri: "https://accounts.example.com/auth".to_string(),
+        token_uri: format!("{}/token", url),
+    };
+
+    let flow = InstalledFlow::new(secret).persist_to(&path).with_consent_handler(consent);
+
+    let transport = crate::transport::default_transport();
+    let first = flow.fetch_token(&*transport, &[super::SPREADSHEETS_SCOPE]).unwrap();
+    let second = flow.fetch_token(&*transport, &[super::SPREADSHEETS_SCOPE]).unwrap();
+    let received = server.join().unwrap();
+    let stored = AuthorizedUser::from_file(&path).unwrap();
+    #[cfg(unix)]
+    let mode = {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::metadata(&path).unwrap().permissions().mode() & 0o777
+    };
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(first.token, "ya29.first");
+    assert_eq!(second.token, "ya29.second");
+    assert!(received[0].body.contains("grant_type=authorization_code&code=4%2Fcode"));
+    assert!(received[0].body.contains("code_verifier="));
+    assert!(received[1].body.contains("grant_type=refresh_token"));
+    assert!(received[1].body.contains("refresh_token=1%2F%2Frefresh"));
+    assert_eq!(stored.refresh_token, "1//refresh");
+    #[cfg(unix)]
+    assert_eq!(mode, 0o600);
+}
+
+#[test]
+fn test_installed_flows_share_store() {
+    use super::{FileTokenStore, TokenProvider, SPREADSHEETS_SCOPE};
+
+    let responses = vec![
+        (200, r#"{"access_token":"ya29.alice","refresh_token":"1//alice","expires_in":3599}"#.to_string()),
+        (200, r#"{"access_token":"ya29.bob","refresh_token":"1//bob","expires_in":3599}"#.to_string()),
+    ];
+    let (url, server) = crate::test_server::serve(responses);
+    let path = std::env::temp_dir().join(format!("google_api_installed_store_{}.json", std::process::id()));
+    let provider = || {
+        let secret = ClientSecret {
+            client_id: "client.apps.googleusercontent.com".to_string(),
+            client_secret: "secret".to_string(),
+            auth_uri: "https://accounts.example.com/auth".to_string(),
+            token_uri: format!("{}/token", url),
+        };
+        let flow = InstalledFlow::new(secret).with_consent_handler(consent);
+
+        TokenProvider::new(flow).with_store(FileTokenStore::new(&path))
+    };
+    let transport = crate::transport::default_transport();
+
+    let alice = provider();
+    assert_eq!(alice.token(&*transport, &[SPREADSHEETS_SCOPE]).unwrap().token, "ya29.alice");
+    // Another user of the same OAuth client consents for themselves instead of picking up Alice's token.
+    let bob = provider();
+    assert_eq!(bob.token(&*transport, &[SPREADSHEETS_SCOPE]).unwrap().token, "ya29.bob");
+    // Both now hit the cache under their account, the server only answered the two consents.
+    assert_eq!(alice.token(&*transport, &[SPREADSHEETS_SCOPE]).unwrap().token, "ya29.alice");
+    assert_eq!(bob.token(&*transport, &[SPREADSHEETS_SCOPE]).unwrap().token, "ya29.bob");
+    server.join().unwrap();
+
+    let stored: std::collections::HashMap<String, serde_json::Value> =
+        serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let user = |refresh_token: &str| {
+        format!("client.apps.googleusercontent.com {} {}", fingerprint(refresh_token), SPREADSHEETS_SCOPE)
+    };
+    assert_eq!(stored.len(), 2);
+    assert!(stored.contains_key(&user("1//alice")));
+    assert!(stored.contains_key(&user("1//bob")));
+}
+
+#[test]
+fn test_receive_code() {
+    use std::io::Read;
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let browser = std::thread::spawn(move || {
+        let get = |target: &str| {
+            let mut stream = TcpStream::connect(address).unwrap();
+            write!(stream, "GET {} HTTP/1.1\r\nHost: 127.0.0.1\r\n\r\n", target).unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            response
+        };
+
+        // Neither a missing nor a wrong state aborts the flow, whatever else the request says.
+        assert!(get("/favicon.ico").starts_with("HTTP/1.1 404"));
+        assert!(get("/?error=access_denied").starts_with("HTTP/1.1 400"));
+        assert!(get("/?state=forged&code=4%2Fforged").starts_with("HTTP/1.1 400"));
+        assert!(get("/?state=expected").starts_with("HTTP/1.1 400"));
+        get("/?state=expected&code=4%2Fcode")
+    });
+
+    assert_eq!(receive_code(&listener, "expected", Duration::from_secs(30)).unwrap(), "4/code");
+    assert!(browser.join().unwrap().contains("Authorization complete"));
+
+    let started = Instant::now();
+    let timed_out = receive_code(&listener, "expected", Duration::from_millis(200));
+    assert!(matches!(timed_out, Err(Error::Credentials(ref message)) if message.contains("timed out")));
+    assert!(started.elapsed() < Duration::from_secs(5));
+}
+//</editor-fold>
@@ -0,0 +1,181 @@
+pub mod adc;
+pub mod api_key;
+pub mod impersonate;
+pub mod installed;
+pub mod provider;
+pub mod service_account;
+
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use serde::Deserialize;
+
+use crate::error::{deserialize, ApiError, Error, Result};
+use crate::transport::{Method, Request, Transport};
+
+pub use adc::MetadataCredentials;
+pub use api_key::ApiKey;
+pub use impersonate::ImpersonatedCredentials;
+pub use installed::{AuthorizedUser, ClientSecret, InstalledFlow};
+pub use provider::{FileTokenStore, TokenProvider, TokenStore};
+pub use service_account::{ServiceAccountCredentials, ServiceAccountKey};
+
+/// See, edit, create, and delete all your Google Sheets spreadsheets.
+pub const SPREADSHEETS_SCOPE: &str = "https://www.googleapis.com/auth/spreadsheets";
+/// See all your Google Sheets spreadsheets.
+pub const SPREADSHEETS_READONLY_SCOPE: &str = "https://www.googleapis.com/auth/spreadsheets.readonly";
+
+/// An OAuth2 access token.
+#[derive(Clone)]
+pub struct AccessToken {
+    /// The bearer token sent in the `Authorization` header.
+    pub token: String,
+    /// When the token stops being accepted, if the server said so.
+    pub expires_at: Option<SystemTime>,
+}
+
+impl AccessToken {
+    /// The value of the `Authorization` header.
+    pub fn header_value(&self) -> String {
+        format!("Bearer {}", self.token)
+    }
+}
+
+/// A source of OAuth2 access tokens.
+pub trait Credentials: Send + Sync {
+    /// Fetches a new access token granting `scopes`, sending any request through `transport`.
+    fn fetch_token(&self, transport: &dyn Transport, scopes: &[&str]) -> Result<AccessToken>;
+
+    /// Who the tokens are issued to, e.g. a service account email, keeping apart the tokens of
+    /// different accounts in a shared `TokenStore`. Holds no secret since it's written as is.
+    fn account(&self) -> Option<String> {
+        None
+    }
+}
+
+impl<C: Credentials + ?Sized> Credentials for Arc<C> {
+    fn fetch_token(&self, transport: &dyn Transport, scopes: &[&str]) -> Result<AccessToken> {
+        (**self).fetch_token(transport, scopes)
+    }
+
+    fn account(&self) -> Option<String> {
+        (**self).account()
+    }
+}
+
+/// How requests are authenticated.
+#[derive(Clone, Debug, Default)]
+pub enum Auth {
+    /// No credentials, public spreadsheets only.
+    #[default]
+    Anonymous,
+    /// The `key` query parameter, read-only access to public spreadsheets.
+    ApiKey(ApiKey),
+    /// OAuth2 bearer tokens.
+    Token(Arc<TokenProvider>),
+}
+
+impl Auth {
+    /// Adds the API key or the `Authorization` header to `request`.
+    pub(crate) fn apply(&self, transport: &dyn Transport, mut request: Request, scopes: &[&str]) -> Result<Request> {
+        match self {
+            Self::Anonymous => {}
+            Self::ApiKey(key) => request.url = key.append_to(&request.url),
+            Self::Token(tokens) => {
+                let token = tokens.token(transport, scopes)?;
+                request = request.header("Authorization", token.header_value());
+            }
+        }
+
+        Ok(request)
+    }
+
+    /// Drops the token `request` was authorized with, once the server rejected it.
+    /// Returns whether sending `request` again could be answered differently.
+    pub(crate) fn reject(&self, request: &Request, scopes: &[&str]) -> bool {
+        let token = request
+            .headers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case("Authorization"))
+            .and_then(|(_, value)| value.strip_prefix("Bearer "));
+
+        match (self, token) {
+            (Self::Token(tokens), Some(token)) => {
+                tokens.invalidate_token(scopes, token);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Scrubs secrets from an error message.
+    pub(crate) fn redact(&self, error: Error) -> Error {
+        match self {
+            Self::ApiKey(key) => error.redact(|text| key.redact(text)),
+            _ => error,
+        }
+    }
+}
+
+/// A short, one-way digest of `secret`, to tell accounts apart without revealing it.
+pub(crate) fn fingerprint(secret: &str) -> String {
+    use sha2::{Digest, Sha256};
+
+    Sha256::digest(secret.as_bytes())[..8].iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// A successful response of an OAuth2 token endpoint.
+#[derive(Deserialize)]
+pub(crate) struct TokenResponse {
+    pub access_token: String,
+    pub expires_in: Option<u64>,
+    pub refresh_token: Option<String>,
+}
+
+impl From<TokenResponse> for AccessToken {
+    fn from(response: TokenResponse) -> Self {
+        Self {
+            token: response.access_token,
+            expires_at: response.expires_in.map(|seconds| SystemTime::now() + Duration::from_secs(seconds)),
+        }
+    }
+}
+
+/// An error response of an OAuth2 token endpoint (RFC 6749, section 5.2).
+#[derive(Deserialize)]
+struct TokenError {
+    error: String,
+    error_description: Option<String>,
+}
+
+/// Posts a form to an OAuth2 token endpoint and decodes the issued token.
+pub(crate) fn request_token(transport: &dyn Transport, token_uri: &str, form: &[(&str, &str)]) -> Result<TokenResponse> {
+    let body = form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(form)
+        .finish();
+    let request = Request::new(Method::Post, token_uri).body("application/x-www-form-urlencoded", body);
+    let response = transport.send(&request)?;
+
+    match response.status {
+        200 => deserialize(&response.body),
+        status => Err(token_error(status, &response.body)),
+    }
+}
+
+/// Token endpoints answer with the RFC 6749 error shape rather than the Google API envelope.
+fn token_error(status: u32, body: &[u8]) -> Error {
+    let error = match serde_json::from_slice::<TokenError>(body) {
+        Ok(e) => ApiError {
+            code: status,
+            message: e.error_description.unwrap_or_default(),
+            status: e.error,
+            details: vec![],
+        },
+        Err(_) => ApiError::from_body(status, body),
+    };
+
+    match status {
+        429 => Error::Quota { status, error },
+        _ => Error::Auth { status, error },
+    }
+}
@@ -0,0 +1,456 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use super::installed::write_private_file;
+use super::{AccessToken, Credentials};
+use crate::error::{Error, Result};
+use crate::transport::Transport;
+
+/// How long before expiry a cached token is considered stale and refreshed.
+const DEFAULT_REFRESH_MARGIN: Duration = Duration::from_secs(5 * 60);
+
+/// A persistent storage of access tokens, keyed by the account of the credentials and the requested scopes.
+pub trait TokenStore: Send + Sync {
+    /// Returns the token stored under `key`, if any.
+    fn load(&self, key: &str) -> Option<AccessToken>;
+    /// Stores `token` under `key`, replacing any previous one.
+    fn store(&self, key: &str, token: &AccessToken) -> Result<()>;
+    /// Forgets the token stored under `key`, once the server rejected it.
+    /// Does nothing by default: the provider stops reading a key it invalidated either way.
+    fn remove(&self, _key: &str) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredToken {
+    access_token: String,
+    /// Seconds since the unix epoch.
+    expires_at: Option<u64>,
+}
+
+/// A `TokenStore` keeping every token in a single JSON file, readable by the current user only.
+pub struct FileTokenStore {
+    path: PathBuf,
+    lock: Mutex<()>,
+}
+
+impl FileTokenStore {
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        Self {
+            path: path.into(),
+            lock: Mutex::new(()),
+        }
+    }
+
+    fn read(&self) -> HashMap<String, StoredToken> {
+        std::fs::read(&self.path)
+            .ok()
+            .and_then(|data| serde_json::from_slice(&data).ok())
+            .unwrap_or_default()
+    }
+
+    fn write(&self, tokens: &HashMap<String, StoredToken>) -> Result<()> {
+        let data = serde_json::to_vec_pretty(tokens).map_err(|e| Error::Credentials(e.to_string()))?;
+
+        write_private_file(&self.path, &data)
+            .map_err(|e| Error::Credentials(format!("couldn't write {}: {}", self.path.display(), e)))
+    }
+}
+
+impl TokenStore for FileTokenStore {
+    fn load(&self, key: &str) -> Option<AccessToken> {
+        let _guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+
+        self.read().remove(key).map(|stored| AccessToken {
+            token: stored.access_token,
+            expires_at: stored.expires_at.map(|secs| UNIX_EPOCH + Duration::from_secs(secs)),
+        })
+    }
+
+    fn store(&self, key: &str, token: &AccessToken) -> Result<()> {
+        let _guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+
+        let mut tokens = self.read();
+        tokens.insert(
+            key.to_string(),
+            StoredToken {
+                access_token: token.token.clone(),
+                expires_at: token
+                    .expires_at
+                    .and_then(|at| at.duration_since(UNIX_EPOCH).ok())
+                    .map(|since| since.as_secs()),
+            },
+        );
+        self.write(&tokens)
+    }
+
+    fn remove(&self, key: &str) -> Result<()> {
+        let _guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+
+        let mut tokens = self.read();
+        match tokens.remove(key) {
+            Some(_) => self.write(&tokens),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The cached token of a set of scopes.
+#[derive(Default)]
+struct Slot {
+    token: Option<AccessToken>,
+    /// Whether the store was already read, or must not be since its token was rejected.
+    store_checked: bool,
+}
+
+/// Hands out cached access tokens, refreshing them shortly before they expire.
+///
+/// Tokens are cached per set of scopes. Concurrent requests for the same scopes wait for
+/// a single refresh instead of each hitting the token endpoint. A token the server rejects
+/// is dropped through `invalidate_token`, from the store too, and never read back.
+pub struct TokenProvider {
+    credentials: Arc<dyn Credentials>,
+    store: Option<Box<dyn TokenStore>>,
+    refresh_margin: Duration,
+    slots: Mutex<HashMap<String, Arc<Mutex<Slot>>>>,
+}
+
+impl TokenProvider {
+    pub fn new<C: Credentials + 'static>(credentials: C) -> Self {
+        Self::from_arc(Arc::new(credentials))
+    }
+
+    pub fn from_arc(credentials: Arc<dyn Credentials>) -> Self {
+        Self {
+            credentials,
+            store: None,
+            refresh_margin: DEFAULT_REFRESH_MARGIN,
+            slots: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Persists issued tokens in `store` and reuses them across processes.
+    pub fn with_store<S: TokenStore + 'static>(mut self, store: S) -> Self {
+        self.store = Some(Box::new(store));
+        self
+    }
+
+    /// Refreshes tokens `margin` before they expire, 5 minutes by default.
+    pub fn with_refresh_margin(mut self, margin: Duration) -> Self {
+        self.refresh_margin = margin;
+        self
+    }
+
+    /// Returns a valid access token granting `scopes`.
+    pub fn token(&self, transport: &dyn Transport, scopes: &[&str]) -> Result<AccessToken> {
+        let key = self.cache_key(scopes);
+        let slot = self.slot(&key);
+        // Held across the refresh, so concurrent callers wait for it rather than duplicate it.
+        let mut cached = slot.lock().unwrap_or_else(|e| e.into_inner());
+
+        if let Some(token) = cached.token.as_ref().filter(|token| self.is_fresh(token)) {
+            return Ok(token.clone());
+        }
+        if !cached.store_checked {
+            cached.store_checked = true;
+            if let Some(token) = self.store.as_ref().and_then(|store| store.load(&key)) {
+                if self.is_fresh(&token) {
+                    cached.token = Some(token.clone());
+                    return Ok(token);
+                }
+            }
+        }
+
+        let token = self.credentials.fetch_token(transport, scopes)?;
+        // The account may only be known now, e.g. once a user consented, so is the key to keep the token under.
+        let issued_key = self.cache_key(scopes);
+        if let Some(store) = &self.store {
+            store.store(&issued_key, &token)?;
+        }
+        if issued_key == key {
+            cached.token = Some(token.clone());
+        } else {
+            let slot = self.slot(&issued_key);
+            let mut issued = slot.lock().unwrap_or_else(|e| e.into_inner());
+            issued.token = Some(token.clone());
+            issued.store_checked = true;
+        }
+
+        Ok(token)
+    }
+
+    /// Drops the cached `token` granting `scopes`, e.g. after the server answered 401 to it,
+    /// so the next call fetches a new one. A token refreshed in the meantime is kept.
+    pub fn invalidate_token(&self, scopes: &[&str], token: &str) {
+        let key = self.cache_key(scopes);
+        let slot = self.slot(&key);
+        let mut cached = slot.lock().unwrap_or_else(|e| e.into_inner());
+
+        if cached.token.as_ref().is_some_and(|cached| cached.token == token) {
+            self.forget(&key, &mut cached);
+        }
+    }
+
+    /// Drops every cached token, stored ones included.
+    pub fn invalidate(&self) {
+        let slots: Vec<_> = self.slots.lock().unwrap_or_else(|e| e.into_inner()).clone().into_iter().collect();
+
+        for (key, slot) in slots {
+            self.forget(&key, &mut slot.lock().unwrap_or_else(|e| e.into_inner()));
+        }
+    }
+
+    fn slot(&self, key: &str) -> Arc<Mutex<Slot>> {
+        self.slots
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .entry(key.to_string())
+            .or_default()
+            .clone()
+    }
+
+    fn forget(&self, key: &str, slot: &mut Slot) {
+        slot.token = None;
+        slot.store_checked = true;
+        if let Some(store) = &self.store {
+            // Not read again by this provider either way, removing it only spares other processes a 401.
+            let _ = store.remove(key);
+        }
+    }
+
+    /// The account and the sorted scopes, e.g. `sa@project.iam.gserviceaccount.com https://www.googleapis.com/auth/spreadsheets`.
+    fn cache_key(&self, scopes: &[&str]) -> String {
+        let mut scopes = scopes.to_vec();
+        scopes.sort_unstable();
+        scopes.dedup();
+
+        match self.credentials.account() {
+            Some(account) => format!("{} {}", account, scopes.join(" ")),
+            None => scopes.join(" "),
+        }
+    }
+
+    fn is_fresh(&self, token: &AccessToken) -> bool {
+        match token.expires_at {
+            Some(expires_at) => SystemTime::now() + self.refresh_margin < expires_at,
+            None => true,
+        }
+    }
+}
+
+impl std::fmt::Debug for TokenProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TokenProvider")
+            .field("refresh_margin", &self.refresh_margin)
+            .finish()
+    }
+}
+
+//<editor-fold desc="Tests">
+
+#[cfg(test)]
+struct CountingCredentials {
+    calls: std::sync::atomic::AtomicUsize,
+    lifetime: Duration,
+}
+
+#[cfg(test)]
+impl Credentials for CountingCredentials {
+    fn fetch_token(&self, _transport: &dyn Transport, _scopes: &[&str]) -> Result<AccessToken> {
+        let n = self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        std::thread::sleep(Duration::from_millis(20));
+
+        Ok(AccessToken {
+            token: format!("token-{}", n),
+            expires_at: Some(SystemTime::now() + self.lifetime),
+        })
+    }
+}
+
+#[test]
+fn test_token_cache() {
+    let credentials = Arc::new(CountingCredentials {
+        calls: Default::default(),
+        lifetime: Duration::from_secs(3600),
+    });
+    let provider = Arc::new(TokenProvider::from_arc(credentials.clone()));
+
+    let threads: Vec<_> = (0..8)
+        .map(|_| {
+            let provider = provider.clone();
+            std::thread::spawn(move || provider.token(&*crate::transport::default_transport(), &["b", "a"]).unwrap().token)
+        })
+        .collect();
+    for thread in threads {
+        assert_eq!(thread.join().unwrap(), "token-0");
+    }
+    assert_eq!(provider.token(&*crate::transport::default_transport(), &["a", "b"]).unwrap().token, "token-0");
+    assert_eq!(provider.token(&*crate::transport::default_transport(), &["a"]).unwrap().token, "token-1");
+    assert_eq!(credentials.calls.load(std::sync::atomic::Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_token_refresh_before_expiry() {
+    let provider = TokenProvider::new(CountingCredentials {
+        calls: Default::default(),
+        lifetime: Duration::from_secs(60),
+    });
+
+    assert_eq!(provider.token(&*crate::transport::default_transport(), &["a"]).unwrap().token, "token-0");
+    // Expires within the default margin, so every call refreshes.
+    assert_eq!(provider.token(&*crate::transport::default_transport(), &["a"]).unwrap().token, "token-1");
+}
+
+#[test]
+fn test_file_token_store() {
+    let path = std::env::temp_dir().join(format!("google_api_tokens_{}.json", std::process::id()));
+    let credentials = || CountingCredentials {
+        calls: Default::default(),
+        lifetime: Duration::from_secs(3600),
+    };
+
+    let first = TokenProvider::new(credentials()).with_store(FileTokenStore::new(&path));
+    assert_eq!(first.token(&*crate::transport::default_transport(), &["a"]).unwrap().token, "token-0");
+    // A new provider, e.g. in the next process run, reuses the stored token.
+    let second = TokenProvider::new(credentials()).with_store(FileTokenStore::new(&path));
+    assert_eq!(second.token(&*crate::transport::default_transport(), &["a"]).unwrap().token, "token-0");
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        assert_eq!(std::fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+    }
+
+    // A rejected token is removed from the file, so the next process doesn't pick it up.
+    second.invalidate_token(&["a"], "token-0");
+    assert!(FileTokenStore::new(&path).load("a").is_none());
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[cfg(test)]
+struct NamedCredentials {
+    account: &'static str,
+    calls: std::sync::atomic::AtomicUsize,
+}
+
+#[cfg(test)]
+impl NamedCredentials {
+    fn new(account: &'static str) -> Arc<Self> {
+        Arc::new(Self {
+            account,
+            calls: Default::default(),
+        })
+    }
+}
+
+#[cfg(test)]
+impl Credentials for NamedCredentials {
+    fn fetch_token(&self, _transport: &dyn Transport, _scopes: &[&str]) -> Result<AccessToken> {
+        let n = self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+
+        Ok(AccessToken {
+            token: format!("{}-{}", self.account, n),
+            expires_at: None,
+        })
+    }
+
+    fn account(&self) -> Option<String> {
+        Some(self.account.to_string())
+    }
+}
+
+/// A `TokenStore` without `remove`, shared by its clones.
+#[cfg(test)]
+#[derive(Clone, Default)]
+struct MemoryTokenStore(Arc<Mutex<HashMap<String, String>>>);
+
+#[cfg(test)]
+impl TokenStore for MemoryTokenStore {
+    fn load(&self, key: &str) -> Option<AccessToken> {
+        self.0.lock().unwrap().get(key).map(|token| AccessToken {
+            token: token.clone(),
+            expires_at: None,
+        })
+    }
+
+    fn store(&self, key: &str, token: &AccessToken) -> Result<()> {
+        self.0.lock().unwrap().insert(key.to_string(), token.token.clone());
+        Ok(())
+    }
+}
+
+#[test]
+fn test_token_store_accounts() {
+    let store = MemoryTokenStore::default();
+    let transport = crate::transport::default_transport();
+
+    let alice = TokenProvider::from_arc(NamedCredentials::new("alice")).with_store(store.clone());
+    let bob = TokenProvider::from_arc(NamedCredentials::new("bob")).with_store(store.clone());
+    assert_eq!(alice.token(&*transport, &["a"]).unwrap().token, "alice-0");
+    assert_eq!(bob.token(&*transport, &["a"]).unwrap().token, "bob-0");
+
+    let mut keys: Vec<_> = store.0.lock().unwrap().keys().cloned().collect();
+    keys.sort();
+    assert_eq!(keys, ["alice a", "bob a"]);
+}
+
+#[test]
+fn test_invalidate_token() {
+    let store = MemoryTokenStore::default();
+    let transport = crate::transport::default_transport();
+    let provider = TokenProvider::from_arc(NamedCredentials::new("alice")).with_store(store.clone());
+
+    assert_eq!(provider.token(&*transport, &["a"]).unwrap().token, "alice-0");
+    // Another token was rejected, e.g. one replaced by a concurrent refresh already.
+    provider.invalidate_token(&["a"], "alice-9");
+    assert_eq!(provider.token(&*transport, &["a"]).unwrap().token, "alice-0");
+
+    // The store can't remove the rejected token, it's bypassed instead.
+    provider.invalidate_token(&["a"], "alice-0");
+    assert_eq!(provider.token(&*transport, &["a"]).unwrap().token, "alice-1");
+    provider.invalidate();
+    assert_eq!(provider.token(&*transport, &["a"]).unwrap().token, "alice-2");
+    assert_eq!(store.load("alice a").unwrap().token, "alice-2");
+}
+
+#[test]
+fn test_client_reauthorizes() {
+    use crate::call::Call;
+    use crate::client::{Client, Version};
+    use crate::transport::{Method, Request, Response};
+
+    let credentials = NamedCredentials::new("alice");
+    let transport = |request: &Request| -> Result<Response> {
+        let revoked = request.headers.iter().any(|(_, value)| value == "Bearer alice-0");
+        Ok(Response {
+            status: if revoked { 401 } else { 200 },
+            headers: vec![],
+            body: b"{}".to_vec(),
+        })
+    };
+    let client = Client::new(Version::V4)
+        .with_transport(transport)
+        .with_token_provider(TokenProvider::from_arc(credentials.clone()));
+
+    let get = || Call::<serde_json::Value>::new(Request::new(Method::Get, "spreadsheets/id"), &["a"]);
+    assert!(client.execute(get()).is_ok());
+    assert!(client.execute(get()).is_ok());
+    assert_eq!(credentials.calls.load(std::sync::atomic::Ordering::SeqCst), 2);
+
+    // Without credentials there's nothing to refresh, the 401 is returned right away.
+    let transport = |_: &Request| -> Result<Response> {
+        Ok(Response {
+            status: 401,
+            headers: vec![],
+            body: vec![],
+        })
+    };
+    let client = Client::new(Version::V4).with_transport(transport);
+    assert_eq!(client.execute(get()).unwrap_err().status(), Some(401));
+}
+//</editor-fold>
@@ -0,0 +1,246 @@
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use rsa::pkcs1v15::SigningKey;
+use rsa::pkcs8::DecodePrivateKey;
+use rsa::signature::{SignatureEncoding, Signer};
+use rsa::RsaPrivateKey;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use super::{request_token, AccessToken, Credentials};
+use crate::error::{deserialize, Error, Result};
+use crate::transport::Transport;
+
+const JWT_BEARER_GRANT: &str = "urn:ietf:params:oauth:grant-type:jwt-bearer";
+/// Lifetime of a signed assertion; Google rejects anything longer than an hour.
+const ASSERTION_LIFETIME: u64 = 3600;
+
+/// A service-account JSON key file, as downloaded from the Cloud console.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ServiceAccountKey {
+    /// Always `service_account`.
+    #[serde(rename = "type")]
+    pub key_type: String,
+    pub project_id: Option<String>,
+    pub private_key_id: Option<String>,
+    /// The PKCS#8 PEM encoded RSA private key.
+    pub private_key: String,
+    pub client_email: String,
+    pub client_id: Option<String>,
+    /// The OAuth2 token endpoint the assertion is exchanged at.
+    pub token_uri: String,
+}
+
+impl ServiceAccountKey {
+    /// Reads a key file from disk.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let data = std::fs::read(path)
+            .map_err(|e| Error::Credentials(format!("couldn't read {}: {}", path.display(), e)))?;
+
+        deserialize(&data)
+    }
+}
+
+impl std::fmt::Debug for ServiceAccountKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ServiceAccountKey")
+            .field("client_email", &self.client_email)
+            .field("private_key_id", &self.private_key_id)
+            .field("token_uri", &self.token_uri)
+            .finish()
+    }
+}
+
+#[derive(Serialize)]
+struct Header<'a> {
+    alg: &'a str,
+    typ: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    kid: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct Claims<'a> {
+    iss: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sub: Option<&'a str>,
+    scope: String,
+    aud: &'a str,
+    iat: u64,
+    exp: u64,
+}
+
+/// Credentials of a service account, exchanging a signed RS256 JWT for access tokens.
+pub struct ServiceAccountCredentials {
+    key: ServiceAccountKey,
+    signing_key: SigningKey<Sha256>,
+    subject: Option<String>,
+}
+
+impl ServiceAccountCredentials {
+    pub fn new(key: ServiceAccountKey) -> Result<Self> {
+        let private_key = RsaPrivateKey::from_pkcs8_pem(&key.private_key)
+            .map_err(|e| Error::Credentials(format!("invalid private key: {}", e)))?;
+
+        Ok(Self {
+            key,
+            signing_key: SigningKey::new(private_key),
+            subject: None,
+        })
+    }
+
+    /// Loads the credentials from a service-account JSON key file.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::new(ServiceAccountKey::from_file(path)?)
+    }
+
+    /// Mints tokens on behalf of `subject`, a user of the Workspace domain.
+    /// Requires domain-wide delegation to be granted to the service account.
+    pub fn with_subject<S: Into<String>>(mut self, subject: S) -> Self {
+        self.subject = Some(subject.into());
+        self
+    }
+
+    pub fn key(&self) -> &ServiceAccountKey {
+        &self.key
+    }
+
+    /// Builds the signed JWT assertion for `scopes`.
+    fn assertion(&self, scopes: &[&str]) -> Result<String> {
+        let iat = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| Error::Credentials(e.to_string()))?
+            .as_secs();
+        let header = Header {
+            alg: "RS256",
+            typ: "JWT",
+            kid: self.key.private_key_id.as_deref(),
+        };
+        let claims = Claims {
+            iss: &self.key.client_email,
+            sub: self.subject.as_deref(),
+            scope: scopes.join(" "),
+            aud: &self.key.token_uri,
+            iat,
+            exp: iat + ASSERTION_LIFETIME,
+        };
+
+        let mut message = encode_segment(&header)?;
+        message.push('.');
+        message.push_str(&encode_segment(&claims)?);
+
+        let signature = self.signing_key.sign(message.as_bytes());
+        message.push('.');
+        message.push_str(&URL_SAFE_NO_PAD.encode(signature.to_bytes()));
+
+        Ok(message)
+    }
+}
+
+impl Credentials for ServiceAccountCredentials {
+    fn fetch_token(&self, transport: &dyn Transport, scopes: &[&str]) -> Result<AccessToken> {
+        let assertion = self.assertion(scopes)?;
+
+        request_token(
+            transport,
+            &self.key.token_uri,
+            &[("grant_type", JWT_BEARER_GRANT), ("assertion", &assertion)],
+        )
+        .map(AccessToken::from)
+    }
+
+    fn account(&self) -> Option<String> {
+        match &self.subject {
+            Some(subject) => Some(format!("{} as {}", self.key.client_email, subject)),
+            None => Some(self.key.client_email.clone()),
+        }
+    }
+}
+
+fn encode_segment<T: Serialize>(value: &T) -> Result<String> {
+    let json = serde_json::to_vec(value).map_err(|e| Error::Credentials(e.to_string()))?;
+
+    Ok(URL_SAFE_NO_PAD.encode(json))
+}
+
+//<editor-fold desc="Tests">
+
+#[cfg(test)]
+fn test_key(token_uri: &str) -> (ServiceAccountKey, rsa::RsaPublicKey) {
+    use rsa::pkcs8::{EncodePrivateKey, LineEnding};
+
+    let private_key = RsaPrivateKey::new(&mut rand::thread_rng(), 1024).unwrap();
+    let public_key = private_key.to_public_key();
+    let key = ServiceAccountKey {
+        key_type: "service_account".to_string(),
+        project_id: Some("test-project".to_string()),
+        private_key_id: Some("key-1".to_string()),
+        private_key: private_key.to_pkcs8_pem(LineEnding::LF).unwrap().to_string(),
+        client_email: "robot@test-project.iam.gserviceaccount.com".to_string(),
+        client_id: None,
+        token_uri: token_uri.to_string(),
+    };
+
+    (key, public_key)
+}
+
+#[test]
+fn test_assertion_is_signed() {
+    use rsa::pkcs1v15::{Signature, VerifyingKey};
+    use rsa::signature::Verifier;
+    use std::convert::TryFrom;
+
+    let (key, public_key) = test_key("https://oauth2.googleapis.com/token");
+    let credentials = ServiceAccountCredentials::new(key).unwrap();
+    let assertion = credentials.assertion(&[super::SPREADSHEETS_SCOPE]).unwrap();
+
+    let (message, signature) = assertion.rsplit_once('.').unwrap();
+    let signature = Signature::try_from(URL_SAFE_NO_PAD.decode(signature).unwrap().as_slice()).unwrap();
+    VerifyingKey::<Sha256>::new(public_key)
+        .verify(message.as_bytes(), &signature)
+        .unwrap();
+
+    let claims: serde_json::Value =
+        serde_json::from_slice(&URL_SAFE_NO_PAD.decode(message.split('.').nth(1).unwrap()).unwrap()).unwrap();
+    assert_eq!(claims["iss"], "robot@test-project.iam.gserviceaccount.com");
+    assert_eq!(claims["aud"], "https://oauth2.googleapis.com/token");
+    assert_eq!(claims["scope"], super::SPREADSHEETS_SCOPE);
+    assert!(claims.get("sub").is_none());
+}
+
+#[test]
+fn test_assertion_subject() {
+    let (key, _) = test_key("https://oauth2.googleapis.com/token");
+    let credentials = ServiceAccountCredentials::new(key).unwrap().with_subject("user@example.com");
+    let assertion = credentials.assertion(&[super::SPREADSHEETS_SCOPE]).unwrap();
+
+    let claims: serde_json::Value =
+        serde_json::from_slice(&URL_SAFE_NO_PAD.decode(assertion.split('.').nth(1).unwrap()).unwrap()).unwrap();
+    assert_eq!(claims["sub"], "user@example.com");
+}
+
+#[test]
+fn test_token_exchange() {
+    let body = r#"{"access_token":"ya29.test","expires_in":3599,"token_type":"Bearer"}"#;
+    let (url, server) = crate::test_server::serve(vec![(200, body.to_string())]);
+
+    let (key, _) = test_key(&format!("{}/token", url));
+    let token = ServiceAccountCredentials::new(key)
+        .unwrap()
+        .fetch_token(&*crate::transport::default_transport(), &[super::SPREADSHEETS_SCOPE])
+        .unwrap();
+    let received = server.join().unwrap();
+
+    assert_eq!(token.header_value(), "Bearer ya29.test");
+    assert!(token.expires_at.is_some());
+    assert_eq!(received[0].line, "POST /token HTTP/1.1");
+    assert_eq!(received[0].header("Content-Type"), Some("application/x-www-form-urlencoded"));
+    assert!(received[0]
+        .body
+        .starts_with("grant_type=urn%3Aietf%3Aparams%3Aoauth%3Agrant-type%3Ajwt-bearer&assertion="));
+}
+//</editor-fold>
@@ -0,0 +1,95 @@
+//! API calls, built once and sent by either the blocking or the async client.
+
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::error::{deserialize, Error, Result};
+use crate::rate_limit::Operation;
+use crate::transport::{Method, Request, Response};
+
+/// A request to the API along with the scopes it needs and how to decode its response.
+pub(crate) struct Call<T> {
+    pub request: Request,
+    pub scopes: &'static [&'static str],
+    /// Whether sending the request twice has the same effect as sending it once, so it can be retried.
+    pub idempotent: bool,
+    /// The quota the call counts against.
+    pub operation: Operation,
+    pub decode: fn(&Response) -> Result<T>,
+    /// Why the request couldn't be built, returned instead of sending it.
+    pub error: Option<Error>,
+}
+
+impl<T: DeserializeOwned> Call<T> {
+    /// A call answered with a JSON body decoded into `T`.
+    /// It's idempotent unless it's a POST or a PATCH, and a read if it's a GET.
+    pub fn new(request: Request, scopes: &'static [&'static str]) -> Self {
+        Self {
+            idempotent: !matches!(request.method, Method::Post | Method::Patch),
+            operation: match request.method {
+                Method::Get => Operation::Read,
+                _ => Operation::Write,
+            },
+            request,
+            scopes,
+            decode: decode_json::<T>,
+            error: None,
+        }
+    }
+}
+
+impl<T> Call<T> {
+    /// Marks a POST as safe to send twice, e.g. clearing a range.
+    pub fn idempotent(mut self) -> Self {
+        self.idempotent = true;
+        self
+    }
+
+    /// Marks a POST that only reads, e.g. a lookup by data filter, so it's retried and counted as a read.
+    pub fn read_only(mut self) -> Self {
+        self.operation = Operation::Read;
+        self.idempotent()
+    }
+
+    /// Sends `body` as JSON, or fails if it holds an `Unknown` enum value read from a response.
+    pub fn with_json<B: Serialize>(mut self, body: &B) -> Self {
+        match serde_json::to_vec(body) {
+            Ok(body) => self.request = self.request.body("application/json", body),
+            Err(e) => self.error = Some(Error::Encode(e.to_string())),
+        }
+        self
+    }
+
+    /// Fails with `error` instead of sending the request.
+    pub fn with_error(mut self, error: Error) -> Self {
+        self.error = Some(error);
+        self
+    }
+}
+
+/// The characters escaped in a path segment, e.g. an A1 range: all but the unreserved ones and `!`.
+const SEGMENT: &AsciiSet = &NON_ALPHANUMERIC.remove(b'-').remove(b'.').remove(b'_').remove(b'~').remove(b'!');
+
+/// `segment` escaped to be a single segment of a url path.
+pub(crate) fn encode_segment(segment: &str) -> String {
+    utf8_percent_encode(segment, SEGMENT).to_string()
+}
+
+/// `path` followed by `params` as its query string, if there are any.
+pub(crate) fn with_query(path: String, params: &[(&str, String)]) -> String {
+    if params.is_empty() {
+        return path;
+    }
+
+    let query = form_urlencoded::Serializer::new(String::new()).extend_pairs(params).finish();
+    format!("{}?{}", path, query)
+}
+
+/// `response` decoded into `T` if it's a success, its error otherwise.
+pub(crate) fn decode_json<T: DeserializeOwned>(response: &Response) -> Result<T> {
+    match response.status {
+        200..=299 => deserialize(&response.body),
+        status => Err(Error::from_response(status, &response.body)),
+    }
+}
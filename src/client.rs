@@ -1,20 +1,196 @@
+use std::sync::Arc;
+
+use super::auth::{adc, ApiKey, Auth, Credentials, TokenProvider};
+use super::call::Call;
+use super::endpoint::{Endpoints, Routed};
+use super::gsheet::GSheetApi;
+use super::error::Result;
+use super::rate_limit::RateLimiter;
+use super::retry::RetryPolicy;
 use super::sheet::{SheetApi};
+use super::transport::{default_transport, http_transport, HttpOptions, Request, Response, Transport};
 
+/// The Sheets API version, selecting the url prefix of calls. `sheet::models` is the v4 model set.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Version {
     V4,
 }
 
+impl Version {
+    /// The url path segment, e.g. `v4`.
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            Version::V4 => "v4",
+        }
+    }
+}
+
+/// The blocking client. Cloning it is cheap, clones share credentials, token cache, transport and rate limiter.
+#[derive(Clone)]
 pub struct Client {
     version: Version,
+    pub(crate) auth: Auth,
+    pub(crate) transport: Arc<dyn Transport>,
+    /// Whether `transport` was set with `with_transport` rather than built from `http`.
+    pub(crate) custom_transport: bool,
+    pub(crate) http: HttpOptions,
+    endpoints: Endpoints,
+    pub(crate) retry: RetryPolicy,
+    pub(crate) limiter: Option<Arc<RateLimiter>>,
 }
 
 impl Client {
 
     pub fn new(version: Version) -> Self {
-        Self { version }
+        Self {
+            version,
+            auth: Auth::Anonymous,
+            transport: default_transport(),
+            custom_transport: false,
+            http: HttpOptions::new(),
+            endpoints: Endpoints::new(),
+            retry: RetryPolicy::new(),
+            limiter: Some(Arc::new(RateLimiter::new())),
+        }
+    }
+
+    /// Authenticates every request made through this client with `credentials`.
+    ///
+    /// Use `ServiceAccountCredentials::with_subject` for domain-wide delegation,
+    /// or wrap any credentials in `ImpersonatedCredentials` to act as another service account.
+    pub fn with_credentials<C: Credentials + 'static>(self, credentials: C) -> Self {
+        self.with_token_provider(TokenProvider::new(credentials))
+    }
+
+    /// Authenticates every request with Application Default Credentials:
+    /// the `GOOGLE_APPLICATION_CREDENTIALS` file, gcloud's well-known file, then the GCE metadata server.
+    pub fn with_default_credentials(self) -> Result<Self> {
+        let credentials = adc::find_credentials(&*self.transport)?;
+
+        Ok(self.with_token_provider(TokenProvider::from_arc(credentials)))
+    }
+
+    /// Authenticates every request with tokens from `provider`, e.g. one backed by a `TokenStore`.
+    pub fn with_token_provider(mut self, provider: TokenProvider) -> Self {
+        self.auth = Auth::Token(Arc::new(provider));
+        self
+    }
+
+    /// Appends `key` to every request, for read-only access to public spreadsheets.
+    pub fn with_api_key<S: Into<String>>(mut self, key: S) -> Self {
+        self.auth = Auth::ApiKey(ApiKey::new(key));
+        self
+    }
+
+    /// Sends every request, API calls and token requests alike, through `transport`.
+    pub fn with_transport<T: Transport + 'static>(mut self, transport: T) -> Self {
+        self.transport = Arc::new(transport);
+        self.custom_transport = true;
+        self
+    }
+
+    /// Sends every request through the built-in transport configured with `options`:
+    /// proxy, timeouts and CA bundle. Fails if the options are invalid.
+    pub fn with_http_options(mut self, options: HttpOptions) -> Result<Self> {
+        self.transport = http_transport(&options)?;
+        self.custom_transport = false;
+        self.http = options;
+        Ok(self)
+    }
+
+    /// Sends API calls and token requests to `endpoints`, e.g. a local fake server.
+    pub fn with_endpoints(mut self, endpoints: Endpoints) -> Self {
+        self.endpoints = endpoints;
+        self
+    }
+
+    /// Retries failed calls according to `policy`, `RetryPolicy::new()` by default.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// Spaces calls out according to `limiter`, `RateLimiter::new()` by default.
+    pub fn with_rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.limiter = Some(Arc::new(limiter));
+        self
     }
 
-    pub fn sheet(self) -> SheetApi {
-        SheetApi::new()
+    /// Sends calls as soon as they are made, e.g. when something else already throttles them.
+    pub fn without_rate_limiter(mut self) -> Self {
+        self.limiter = None;
+        self
+    }
+
+    /// The rate limiter, to monitor its bucket levels.
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.limiter.as_deref()
+    }
+
+    pub fn version(&self) -> &Version {
+        &self.version
+    }
+
+    pub fn endpoints(&self) -> &Endpoints {
+        &self.endpoints
+    }
+
+    /// An API handle sharing this client's settings, connections and token cache.
+    pub fn sheet(&self) -> SheetApi {
+        SheetApi::with_client(self.clone())
+    }
+
+    pub fn gsheet(&self) -> GSheetApi {
+        GSheetApi::with_client(self.clone())
+    }
+
+    /// Authenticates and sends `call`, within the rate limits and retrying as the policy allows,
+    /// then decodes its response.
+    pub(crate) fn execute<T>(&self, call: Call<T>) -> Result<T> {
+        let Call { mut request, scopes, idempotent, operation, decode, error } = call;
+        if let Some(error) = error {
+            return Err(error);
+        }
+        request.url = self.url(&request.url);
+
+        let mut attempt = 1;
+        loop {
+            if let Some(limiter) = &self.limiter {
+                std::thread::sleep(limiter.reserve(operation));
+            }
+            let result = self.send(&request, scopes);
+
+            match self.retry.delay(attempt, idempotent, &result) {
+                Some(delay) => std::thread::sleep(delay),
+                None => return result.and_then(|response| decode(&response)).map_err(|e| self.auth.redact(e)),
+            }
+            attempt += 1;
+        }
+    }
+
+    /// Authenticates and sends `request`, once more with a new token if the server rejected the cached one.
+    fn send(&self, request: &Request, scopes: &[&str]) -> Result<Response> {
+        let authorized = self.authorize(request.clone(), scopes)?;
+        let response = self.transport.send(&authorized)?;
+
+        if response.status == 401 && self.auth.reject(&authorized, scopes) {
+            return self.transport.send(&self.authorize(request.clone(), scopes)?);
+        }
+        Ok(response)
+    }
+
+    /// The url of an API call, `path` being relative to the version prefix, e.g. `spreadsheets/{id}`.
+    pub(crate) fn url(&self, path: &str) -> String {
+        self.endpoints.url(&format!("{}/{}", self.version.prefix(), path))
+    }
+
+    /// Adds credentials to `request`, fetching tokens from the configured endpoints if needed.
+    pub(crate) fn authorize(&self, request: Request, scopes: &[&str]) -> Result<Request> {
+        let transport = Routed {
+            transport: &*self.transport,
+            endpoints: &self.endpoints,
+        };
+
+        self.auth.apply(&transport, request, scopes)
     }
 }
@@ -0,0 +1,155 @@
+//! The few timestamp formats found in responses, without pulling in a date crate.
+
+use std::convert::TryFrom;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const MONTHS: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+
+/// The UTC date and time, `None` if a field is out of range (e.g. `2024-13-45`) or it's before 1970.
+pub(crate) fn system_time(year: i64, month: u32, day: u32, hour: u32, minute: u32, second: u32) -> Option<SystemTime> {
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return None;
+    }
+    if hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+
+    // Days from the civil date, see http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let (month, day) = (i64::from(month), i64::from(day));
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = u64::try_from(era * 146_097 + doe - 719_468).ok()?;
+
+    let seconds = days * 86_400 + u64::from(hour) * 3600 + u64::from(minute) * 60 + u64::from(second);
+    Some(UNIX_EPOCH + Duration::from_secs(seconds))
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Parses an RFC 3339 timestamp, e.g. `2014-10-02T15:01:23.045123456Z` or `2014-10-02T17:01:23+02:00`.
+pub(crate) fn parse_timestamp(text: &str) -> Option<SystemTime> {
+    let (date, time) = text.split_once(['T', 't'])?;
+    let (time, offset) = time.split_at(time.find(['Z', 'z', '+', '-'])?);
+    let (time, nanos) = match time.split_once('.') {
+        Some((time, fraction)) if !fraction.is_empty() && fraction.bytes().all(|b| b.is_ascii_digit()) => {
+            (time, format!("{:0<9}", fraction).get(..9)?.parse::<u32>().ok()?)
+        }
+        Some(_) => return None,
+        None => (time, 0),
+    };
+
+    let mut fields = date.split('-');
+    let (year, month, day) = (digits(fields.next()?, 4)?, digits(fields.next()?, 2)?, digits(fields.next()?, 2)?);
+    if fields.next().is_some() {
+        return None;
+    }
+    let (hour, minute, second) = parse_time(time)?;
+    let local = system_time(i64::from(year), month, day, hour, minute, second)? + Duration::from_nanos(u64::from(nanos));
+
+    if offset == "Z" || offset == "z" {
+        return Some(local);
+    }
+    // How far ahead of UTC the local time is.
+    let (sign, offset) = offset.split_at(1);
+    let (hours, minutes) = offset.split_once(':')?;
+    let (hours, minutes) = (digits(hours, 2)?, digits(minutes, 2)?);
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+    let offset = Duration::from_secs(u64::from(hours * 3600 + minutes * 60));
+
+    match sign {
+        "+" => local.checked_sub(offset),
+        "-" => local.checked_add(offset),
+        _ => None,
+    }
+}
+
+/// Parses an HTTP date, e.g. `Sun, 06 Nov 1994 08:49:37 GMT`.
+/// Only the IMF-fixdate format of RFC 9110, the one senders must use, is understood.
+pub(crate) fn parse_http_date(text: &str) -> Option<SystemTime> {
+    let fields: Vec<_> = text.split_whitespace().collect();
+    let (weekday, day, month, year, time, zone) = match fields[..] {
+        [weekday, day, month, year, time, zone] => (weekday, day, month, year, time, zone),
+        _ => return None,
+    };
+    if !weekday.ends_with(',') || zone != "GMT" || day.len() != 2 {
+        return None;
+    }
+
+    let month = MONTHS.iter().position(|name| *name == month)? as u32 + 1;
+    let (hour, minute, second) = parse_time(time)?;
+
+    system_time(year.parse().ok()?, month, day.parse().ok()?, hour, minute, second)
+}
+
+/// Parses `hh:mm:ss`.
+fn parse_time(text: &str) -> Option<(u32, u32, u32)> {
+    let mut fields = text.split(':').map(|field| digits(field, 2));
+
+    match (fields.next()??, fields.next()??, fields.next()??, fields.next()) {
+        (hour, minute, second, None) => Some((hour, minute, second)),
+        _ => None,
+    }
+}
+
+/// `text` as a number, if it's made of exactly `len` digits.
+fn digits(text: &str, len: usize) -> Option<u32> {
+    match text.len() == len && text.bytes().all(|b| b.is_ascii_digit()) {
+        true => text.parse().ok(),
+        false => None,
+    }
+}
+
+//<editor-fold desc="Tests">
+
+#[test]
+fn test_system_time() {
+    assert_eq!(system_time(1970, 1, 1, 0, 0, 0), Some(UNIX_EPOCH));
+    assert_eq!(system_time(2000, 2, 29, 23, 59, 59), Some(UNIX_EPOCH + Duration::from_secs(951_868_799)));
+    assert_eq!(system_time(1900, 2, 29, 0, 0, 0), None);
+    assert_eq!(system_time(2024, 13, 45, 0, 0, 0), None);
+    assert_eq!(system_time(2024, 4, 31, 0, 0, 0), None);
+    assert_eq!(system_time(2024, 1, 1, 24, 0, 0), None);
+    assert_eq!(system_time(1969, 12, 31, 23, 59, 59), None);
+}
+
+#[test]
+fn test_parse_http_date() {
+    assert_eq!(parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT"), Some(UNIX_EPOCH + Duration::from_secs(784_111_777)));
+    assert_eq!(parse_http_date("Sunday, 06-Nov-94 08:49:37 GMT"), None);
+    assert_eq!(parse_http_date("Sun, 06 Nov 1994 08:49:37 CET"), None);
+    assert_eq!(parse_http_date("Sun, 31 Nov 1994 08:49:37 GMT"), None);
+    assert_eq!(parse_http_date("Sun, 06 Nov 1994 8:49:37 GMT"), None);
+}
+
+#[test]
+fn test_parse_timestamp() {
+    assert_eq!(parse_timestamp("1970-01-01T00:00:00Z"), Some(UNIX_EPOCH));
+    let expected = Some(UNIX_EPOCH + Duration::from_millis(1_412_262_083_500));
+    assert_eq!(parse_timestamp("2014-10-02T15:01:23.5Z"), expected);
+    assert_eq!(parse_timestamp("2014-10-02T17:01:23.5+02:00"), expected);
+    assert_eq!(parse_timestamp("2014-10-02T10:31:23.5-04:30"), expected);
+    assert_eq!(parse_timestamp("2014-10-02t15:01:23.500000000z"), expected);
+
+    assert_eq!(parse_timestamp("2014-10-02 15:01:23"), None);
+    assert_eq!(parse_timestamp("2014-10-02T15:01:23"), None);
+    assert_eq!(parse_timestamp("2024-13-45T00:00:00Z"), None);
+    assert_eq!(parse_timestamp("2023-02-29T00:00:00Z"), None);
+    assert_eq!(parse_timestamp("2014-10-02T25:01:23Z"), None);
+    assert_eq!(parse_timestamp("2014-10-02T15:01:23+24:00"), None);
+    assert_eq!(parse_timestamp("2014-10-02T15:01:23.Z"), None);
+    assert_eq!(parse_timestamp("2014-10-02T15:01:23Z+01:00"), None);
+    assert_eq!(parse_timestamp("2014-+1-02T15:01:23Z"), None);
+}
+//</editor-fold>
@@ -0,0 +1,130 @@
+//! Where API calls and token requests are sent, e.g. to use a local fake server or a regional endpoint.
+
+use crate::error::Result;
+use crate::transport::{Request, Response, Transport};
+
+/// The Sheets API root url.
+pub const DEFAULT_ROOT_URL: &str = "https://sheets.googleapis.com/";
+/// Google's OAuth2 token endpoint.
+pub const DEFAULT_TOKEN_URL: &str = "https://oauth2.googleapis.com/token";
+/// The IAM Credentials API root url, used to impersonate service accounts.
+pub const DEFAULT_IAM_CREDENTIALS_URL: &str = "https://iamcredentials.googleapis.com";
+
+/// The root url of API calls along with replacements of other Google service urls.
+#[derive(Clone, Debug)]
+pub struct Endpoints {
+    root_url: String,
+    services: Vec<(String, String)>,
+}
+
+impl Endpoints {
+    /// Google's production endpoints.
+    pub fn new() -> Self {
+        Self {
+            root_url: DEFAULT_ROOT_URL.to_string(),
+            services: vec![],
+        }
+    }
+
+    /// Sends API calls to `url` instead of `https://sheets.googleapis.com/`.
+    pub fn with_root_url<S: Into<String>>(mut self, url: S) -> Self {
+        self.root_url = url.into();
+        self
+    }
+
+    /// Sends OAuth2 token requests to `url` instead of `https://oauth2.googleapis.com/token`.
+    pub fn with_token_url<S: Into<String>>(self, url: S) -> Self {
+        self.with_service_url(DEFAULT_TOKEN_URL, url)
+    }
+
+    /// Sends any request whose url starts with `default` to `url` instead, e.g. IAM Credentials calls.
+    pub fn with_service_url<D: Into<String>, S: Into<String>>(mut self, default: D, url: S) -> Self {
+        self.services.push((default.into(), url.into()));
+        self
+    }
+
+    pub fn root_url(&self) -> &str {
+        &self.root_url
+    }
+
+    /// The url of an API call, `path` being relative to the root url.
+    pub(crate) fn url(&self, path: &str) -> String {
+        format!("{}/{}", self.root_url.trim_end_matches('/'), path.trim_start_matches('/'))
+    }
+
+    /// `url`, with a replaced service url in place of the default one.
+    pub(crate) fn route(&self, url: &str) -> Option<String> {
+        self.services.iter().find_map(|(default, replacement)| {
+            url.strip_prefix(default.as_str())
+                .map(|rest| format!("{}{}", replacement, rest))
+        })
+    }
+}
+
+impl Default for Endpoints {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A transport sending requests to the replaced service urls, handed over to credentials.
+pub(crate) struct Routed<'a> {
+    pub transport: &'a dyn Transport,
+    pub endpoints: &'a Endpoints,
+}
+
+impl Transport for Routed<'_> {
+    fn send(&self, request: &Request) -> Result<Response> {
+        match self.endpoints.route(&request.url) {
+            Some(url) => self.transport.send(&Request { url, ..request.clone() }),
+            None => self.transport.send(request),
+        }
+    }
+}
+
+//<editor-fold desc="Tests">
+
+#[test]
+fn test_endpoints() {
+    let endpoints = Endpoints::new()
+        .with_root_url("http://localhost:8080")
+        .with_token_url("http://localhost:8081/token")
+        .with_service_url(DEFAULT_IAM_CREDENTIALS_URL, "http://localhost:8082");
+
+    assert_eq!(endpoints.url("v4/spreadsheets/id"), "http://localhost:8080/v4/spreadsheets/id");
+    assert_eq!(endpoints.route(DEFAULT_TOKEN_URL).as_deref(), Some("http://localhost:8081/token"));
+    assert_eq!(
+        endpoints
+            .route("https://iamcredentials.googleapis.com/v1/projects/-/serviceAccounts/sa:generateAccessToken")
+            .as_deref(),
+        Some("http://localhost:8082/v1/projects/-/serviceAccounts/sa:generateAccessToken")
+    );
+    assert_eq!(endpoints.route("https://example.com/"), None);
+    assert_eq!(Endpoints::new().url("/v4/spreadsheets"), "https://sheets.googleapis.com/v4/spreadsheets");
+}
+
+#[test]
+fn test_client_endpoints() {
+    use crate::auth::AuthorizedUser;
+    use crate::client::{Client, Version};
+
+    let (url, server) = crate::test_server::serve(vec![
+        (200, r#"{"access_token":"ya29.fake","expires_in":3599}"#.to_string()),
+        (404, r#"{"error":{"code":404,"message":"Not found","status":"NOT_FOUND"}}"#.to_string()),
+    ]);
+    let user: AuthorizedUser = serde_json::from_str(
+        r#"{"type":"authorized_user","client_id":"id","client_secret":"secret","refresh_token":"1//r"}"#,
+    )
+    .unwrap();
+
+    let client = Client::new(Version::V4)
+        .with_credentials(user)
+        .with_endpoints(Endpoints::new().with_root_url(&url).with_token_url(format!("{}/token", url)));
+    assert_eq!(client.gsheet().get("id").err().and_then(|e| e.status()), Some(404));
+    let received = server.join().unwrap();
+
+    assert_eq!(received[0].line, "POST /token HTTP/1.1");
+    assert_eq!(received[1].line, "GET /v4/spreadsheets/id HTTP/1.1");
+    assert_eq!(received[1].header("Authorization"), Some("Bearer ya29.fake"));
+}
+//</editor-fold>
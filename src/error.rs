@@ -0,0 +1,532 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A type `Error` represents every failure an API call can return.
+#[derive(Debug)]
+pub enum Error {
+    /// The request never produced an HTTP response (DNS, TLS, connection reset, ...).
+    Transport(TransportError),
+    /// The server answered with a status the call does not expect.
+    Http {
+        status: u32,
+        error: ApiError,
+    },
+    /// The response body does not match the expected model.
+    /// `path` points at the offending field, e.g. `sheets[0].properties.sheetId`.
+    Decode {
+        path: String,
+        source: serde_json::Error,
+    },
+    /// The credentials are missing, invalid or were rejected (HTTP 401 and 403).
+    Auth {
+        status: u32,
+        error: ApiError,
+    },
+    /// A rate limit or quota is exhausted (HTTP 429, or 403 with a rate limit reason).
+    Quota {
+        status: u32,
+        error: ApiError,
+    },
+    /// The credentials could not be loaded or used to sign a token request.
+    Credentials(String),
+    /// The request could not be built, e.g. it holds an `Unknown` enum value read from a response.
+    Encode(String),
+}
+
+/// A `Result` alias where the `Err` case is `google_api::error::Error`.
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    /// Builds an error out of a non-successful HTTP response.
+    pub fn from_response(status: u32, body: &[u8]) -> Self {
+        let error = ApiError::from_body(status, body);
+
+        match status {
+            429 => Self::Quota { status, error },
+            403 if error.is_quota_exhausted() => Self::Quota { status, error },
+            401 | 403 => Self::Auth { status, error },
+            _ => Self::Http { status, error },
+        }
+    }
+
+    /// The HTTP status of the response, if the server answered at all.
+    pub fn status(&self) -> Option<u32> {
+        match self {
+            Self::Http { status, .. } | Self::Auth { status, .. } | Self::Quota { status, .. } => Some(*status),
+            Self::Transport(_) | Self::Decode { .. } | Self::Credentials(_) | Self::Encode(_) => None,
+        }
+    }
+
+    /// Applies `redact` to every message carried by the error.
+    pub(crate) fn redact<F: Fn(&str) -> String>(self, redact: F) -> Self {
+        match self {
+            Self::Http { status, error } => Self::Http { status, error: error.redact(redact) },
+            Self::Auth { status, error } => Self::Auth { status, error: error.redact(redact) },
+            Self::Quota { status, error } => Self::Quota { status, error: error.redact(redact) },
+            Self::Credentials(message) => Self::Credentials(redact(&message)),
+            Self::Transport(e) => Self::Transport(e.redact(redact)),
+            error => error,
+        }
+    }
+
+    /// The error envelope returned by the server, if the server answered at all.
+    pub fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            Self::Http { error, .. } | Self::Auth { error, .. } | Self::Quota { error, .. } => Some(error),
+            Self::Transport(_) | Self::Decode { .. } | Self::Credentials(_) | Self::Encode(_) => None,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Transport(e) => write!(f, "transport error: {}", e),
+            Self::Http { status, error } => write!(f, "unexpected status code {}: {}", status, error),
+            Self::Decode { path, source } => write!(f, "couldn't deserialize `{}`: {}", path, source),
+            Self::Auth { status, error } => write!(f, "authentication failed ({}): {}", status, error),
+            Self::Quota { status, error } => write!(f, "quota exhausted ({}): {}", status, error),
+            Self::Credentials(message) => write!(f, "invalid credentials: {}", message),
+            Self::Encode(message) => write!(f, "couldn't build the request: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Transport(e) => Some(e),
+            Self::Decode { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<TransportError> for Error {
+    fn from(e: TransportError) -> Self {
+        Self::Transport(e)
+    }
+}
+
+/// What went wrong while exchanging a request with the server.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransportErrorKind {
+    /// The server could not be resolved or reached, or the connection broke mid-exchange.
+    Connect,
+    /// The exchange did not complete in time.
+    Timeout,
+    /// Anything else, e.g. an invalid url or a TLS failure.
+    Other,
+}
+
+/// A failure of the HTTP transport, wrapping the backend's error.
+#[derive(Debug)]
+pub struct TransportError {
+    kind: TransportErrorKind,
+    source: Box<dyn std::error::Error + Send + Sync>,
+}
+
+impl TransportError {
+    pub fn new<E: Into<Box<dyn std::error::Error + Send + Sync>>>(kind: TransportErrorKind, source: E) -> Self {
+        Self {
+            kind,
+            source: source.into(),
+        }
+    }
+
+    pub fn kind(&self) -> TransportErrorKind {
+        self.kind
+    }
+
+    /// Whether sending the same request again may succeed.
+    pub fn is_transient(&self) -> bool {
+        matches!(self.kind, TransportErrorKind::Connect | TransportErrorKind::Timeout)
+    }
+
+    /// Applies `redact` to the message, which replaces the source if anything was redacted.
+    fn redact<F: Fn(&str) -> String>(self, redact: F) -> Self {
+        let message = self.source.to_string();
+        let redacted = redact(&message);
+
+        if redacted == message {
+            return self;
+        }
+        Self::new(self.kind, redacted)
+    }
+}
+
+impl Display for TransportError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.source)
+    }
+}
+
+impl std::error::Error for TransportError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&*self.source)
+    }
+}
+
+impl From<serde_path_to_error::Error<serde_json::Error>> for Error {
+    fn from(e: serde_path_to_error::Error<serde_json::Error>) -> Self {
+        Self::Decode {
+            path: e.path().to_string(),
+            source: e.into_inner(),
+        }
+    }
+}
+
+/// The error model returned by Google APIs, see <https://cloud.google.com/apis/design/errors>.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ApiError {
+    /// The HTTP status code.
+    pub code: u32,
+    /// A developer-facing, human-readable error message.
+    pub message: String,
+    /// The canonical error code, e.g. `INVALID_ARGUMENT` or `RESOURCE_EXHAUSTED`.
+    #[serde(default)]
+    pub status: String,
+    /// Additional error information.
+    #[serde(default)]
+    pub details: Vec<ErrorDetail>,
+}
+
+#[derive(Deserialize)]
+struct ErrorEnvelope {
+    error: ApiError,
+}
+
+impl ApiError {
+    /// Decodes the `{"error": {...}}` envelope.
+    /// When the body is not an envelope the raw body becomes the message.
+    pub fn from_body(status: u32, body: &[u8]) -> Self {
+        match serde_json::from_slice::<ErrorEnvelope>(body) {
+            Ok(envelope) => envelope.error,
+            Err(_) => Self {
+                code: status,
+                message: String::from_utf8_lossy(body).into_owned(),
+                ..Self::default()
+            },
+        }
+    }
+
+    fn redact<F: Fn(&str) -> String>(mut self, redact: F) -> Self {
+        self.message = redact(&self.message);
+        for detail in &mut self.details {
+            if let ErrorDetail::BadRequest(bad_request) = detail {
+                for violation in &mut bad_request.field_violations {
+                    violation.description = redact(&violation.description);
+                }
+            }
+        }
+
+        self
+    }
+
+    /// The `ErrorInfo` detail, if any.
+    pub fn error_info(&self) -> Option<&ErrorInfo> {
+        self.details.iter().find_map(|detail| match detail {
+            ErrorDetail::ErrorInfo(info) => Some(info),
+            _ => None,
+        })
+    }
+
+    /// Every field violation reported by `BadRequest` details.
+    pub fn field_violations(&self) -> Vec<&FieldViolation> {
+        self.details
+            .iter()
+            .filter_map(|detail| match detail {
+                ErrorDetail::BadRequest(bad_request) => Some(bad_request.field_violations.iter()),
+                _ => None,
+            })
+            .flatten()
+            .collect()
+    }
+
+    /// Every quota violation reported by `QuotaFailure` details.
+    pub fn quota_violations(&self) -> Vec<&QuotaViolation> {
+        self.details
+            .iter()
+            .filter_map(|detail| match detail {
+                ErrorDetail::QuotaFailure(failure) => Some(failure.violations.iter()),
+                _ => None,
+            })
+            .flatten()
+            .collect()
+    }
+
+    /// How long the server asks clients to wait before retrying, from `RetryInfo`.
+    pub fn retry_delay(&self) -> Option<Duration> {
+        self.details.iter().find_map(|detail| match detail {
+            ErrorDetail::RetryInfo(info) => parse_duration(&info.retry_delay),
+            _ => None,
+        })
+    }
+
+    fn is_quota_exhausted(&self) -> bool {
+        self.status == "RESOURCE_EXHAUSTED"
+            || self.details.iter().any(|detail| matches!(detail, ErrorDetail::QuotaFailure(_)))
+            || self
+                .error_info()
+                .is_some_and(|info| info.reason.contains("RATE_LIMIT") || info.reason.contains("QUOTA"))
+    }
+}
+
+impl Display for ApiError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.status.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.status, self.message)
+        }
+    }
+}
+
+const ERROR_INFO_TYPE: &str = "type.googleapis.com/google.rpc.ErrorInfo";
+const BAD_REQUEST_TYPE: &str = "type.googleapis.com/google.rpc.BadRequest";
+const QUOTA_FAILURE_TYPE: &str = "type.googleapis.com/google.rpc.QuotaFailure";
+const RETRY_INFO_TYPE: &str = "type.googleapis.com/google.rpc.RetryInfo";
+
+/// A single entry of `ApiError::details`, discriminated by its `@type` field.
+#[derive(Debug, Clone)]
+pub enum ErrorDetail {
+    ErrorInfo(ErrorInfo),
+    BadRequest(BadRequest),
+    QuotaFailure(QuotaFailure),
+    RetryInfo(RetryInfo),
+    /// A detail type this crate does not model, kept as raw JSON.
+    Other(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for ErrorDetail {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let kind = value.get("@type").and_then(|kind| kind.as_str()).unwrap_or_default();
+
+        let detail = match kind {
+            ERROR_INFO_TYPE => serde_json::from_value(value).map(Self::ErrorInfo),
+            BAD_REQUEST_TYPE => serde_json::from_value(value).map(Self::BadRequest),
+            QUOTA_FAILURE_TYPE => serde_json::from_value(value).map(Self::QuotaFailure),
+            RETRY_INFO_TYPE => serde_json::from_value(value).map(Self::RetryInfo),
+            _ => Ok(Self::Other(value)),
+        };
+
+        detail.map_err(serde::de::Error::custom)
+    }
+}
+
+impl Serialize for ErrorDetail {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let (kind, value) = match self {
+            Self::ErrorInfo(detail) => (ERROR_INFO_TYPE, serde_json::to_value(detail)),
+            Self::BadRequest(detail) => (BAD_REQUEST_TYPE, serde_json::to_value(detail)),
+            Self::QuotaFailure(detail) => (QUOTA_FAILURE_TYPE, serde_json::to_value(detail)),
+            Self::RetryInfo(detail) => (RETRY_INFO_TYPE, serde_json::to_value(detail)),
+            Self::Other(value) => return value.serialize(serializer),
+        };
+        let mut value = value.map_err(serde::ser::Error::custom)?;
+        if let serde_json::Value::Object(map) = &mut value {
+            map.insert("@type".to_string(), kind.into());
+        }
+
+        value.serialize(serializer)
+    }
+}
+
+/// Describes the cause of the error with structured details.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ErrorInfo {
+    /// The reason of the error, e.g. `RATE_LIMIT_EXCEEDED`.
+    #[serde(default)]
+    pub reason: String,
+    /// The logical grouping to which the `reason` belongs, e.g. `googleapis.com`.
+    #[serde(default)]
+    pub domain: String,
+    /// Additional structured details about this error.
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+}
+
+/// Describes violations in a client request.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct BadRequest {
+    /// Describes all violations in a client request.
+    #[serde(rename(serialize = "fieldViolations", deserialize = "fieldViolations"), default)]
+    pub field_violations: Vec<FieldViolation>,
+}
+
+/// A single bad request field.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct FieldViolation {
+    /// A path to a field in the request body, e.g. `requests[0].addSheet.properties.title`.
+    #[serde(default)]
+    pub field: String,
+    /// A description of why the request element is bad.
+    #[serde(default)]
+    pub description: String,
+}
+
+/// Describes how a quota check failed.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct QuotaFailure {
+    /// Describes all quota violations.
+    #[serde(default)]
+    pub violations: Vec<QuotaViolation>,
+}
+
+/// A single quota violation.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct QuotaViolation {
+    /// The subject on which the quota check failed, e.g. `project:123`.
+    #[serde(default)]
+    pub subject: String,
+    /// A description of how the quota check failed.
+    #[serde(default)]
+    pub description: String,
+}
+
+/// Describes when the clients can retry a failed request.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct RetryInfo {
+    /// Clients should wait at least this long between retrying the same request, e.g. `"30s"` or `"1.5s"`.
+    #[serde(rename(serialize = "retryDelay", deserialize = "retryDelay"), default)]
+    pub retry_delay: String,
+}
+
+/// Parses a protobuf JSON duration, e.g. `"30s"` or `"0.250s"`.
+/// Negative, infinite and out of range values, e.g. `"1e300s"`, are rejected.
+fn parse_duration(text: &str) -> Option<Duration> {
+    let seconds: f64 = text.strip_suffix('s')?.parse().ok()?;
+
+    Duration::try_from_secs_f64(seconds).ok()
+}
+
+/// Decodes a JSON body, keeping track of the path to the field that failed.
+pub(crate) fn deserialize<T: serde::de::DeserializeOwned>(data: &[u8]) -> Result<T> {
+    let de = &mut serde_json::Deserializer::from_slice(data);
+
+    Ok(serde_path_to_error::deserialize(de)?)
+}
+
+//<editor-fold desc="Tests">
+
+#[test]
+fn test_status_mapping() {
+    assert!(matches!(Error::from_response(401, b""), Error::Auth { status: 401, .. }));
+    assert!(matches!(Error::from_response(403, b""), Error::Auth { status: 403, .. }));
+    assert!(matches!(Error::from_response(429, b""), Error::Quota { status: 429, .. }));
+    assert!(matches!(Error::from_response(500, b"boom"), Error::Http { status: 500, .. }));
+}
+
+#[test]
+fn test_error_envelope_decode() {
+    let body = serde_json::json!({
+      "error": {
+        "code": 400,
+        "message": "Invalid requests[0].addSheet: A sheet with the name \"Sheet1\" already exists.",
+        "status": "INVALID_ARGUMENT",
+        "details": [
+          {
+            "@type": "type.googleapis.com/google.rpc.BadRequest",
+            "fieldViolations": [
+              {
+                "field": "requests[0].addSheet.properties.title",
+                "description": "A sheet with the name \"Sheet1\" already exists."
+              }
+            ]
+          },
+          {
+            "@type": "type.googleapis.com/google.rpc.RetryInfo",
+            "retryDelay": "1.5s"
+          },
+          {
+            "@type": "type.googleapis.com/google.rpc.Help",
+            "links": []
+          }
+        ]
+      }
+    });
+
+    let err = Error::from_response(400, body.to_string().as_bytes());
+    let api_error = err.api_error().unwrap();
+
+    assert!(matches!(err, Error::Http { status: 400, .. }));
+    assert_eq!(api_error.status, "INVALID_ARGUMENT");
+    assert_eq!(api_error.field_violations()[0].field, "requests[0].addSheet.properties.title");
+    assert_eq!(api_error.retry_delay(), Some(Duration::from_millis(1500)));
+    assert!(matches!(api_error.details[2], ErrorDetail::Other(_)));
+}
+
+#[test]
+fn test_parse_duration() {
+    assert_eq!(parse_duration("30s"), Some(Duration::from_secs(30)));
+    assert_eq!(parse_duration("0.250s"), Some(Duration::from_millis(250)));
+    assert_eq!(parse_duration("1e300s"), None);
+    assert_eq!(parse_duration("-1s"), None);
+    assert_eq!(parse_duration("infs"), None);
+    assert_eq!(parse_duration("30"), None);
+}
+
+#[test]
+fn test_redact() {
+    let body = br#"<p>The requested URL <code>/v4/spreadsheets/id?key=AIzaSecret</code> was not found.</p>"#;
+    let err = Error::from_response(404, body).redact(|text| text.replace("AIzaSecret", "<redacted>"));
+
+    assert!(!err.to_string().contains("AIzaSecret"));
+    assert!(err.to_string().contains("key=<redacted>"));
+
+    let source = "error sending request for url (https://sheets.googleapis.com/v4/spreadsheets/id?key=AIzaSecret)";
+    let err = Error::Transport(TransportError::new(TransportErrorKind::Connect, source))
+        .redact(|text| text.replace("AIzaSecret", "<redacted>"));
+
+    assert!(!err.to_string().contains("AIzaSecret"));
+    assert!(!format!("{:?}", err).contains("AIzaSecret"));
+    assert!(matches!(&err, Error::Transport(e) if e.kind() == TransportErrorKind::Connect));
+}
+
+#[test]
+fn test_rate_limit_forbidden_is_quota() {
+    let body = serde_json::json!({
+      "error": {
+        "code": 403,
+        "message": "Quota exceeded for quota metric 'Read requests'.",
+        "status": "PERMISSION_DENIED",
+        "details": [
+          {
+            "@type": "type.googleapis.com/google.rpc.ErrorInfo",
+            "reason": "RATE_LIMIT_EXCEEDED",
+            "domain": "googleapis.com",
+            "metadata": {"service": "sheets.googleapis.com"}
+          }
+        ]
+      }
+    });
+
+    let err = Error::from_response(403, body.to_string().as_bytes());
+
+    assert!(matches!(err, Error::Quota { status: 403, .. }));
+    assert_eq!(err.api_error().unwrap().error_info().unwrap().reason, "RATE_LIMIT_EXCEEDED");
+}
+
+#[test]
+fn test_decode_error_path() {
+    #[derive(serde::Deserialize, Debug)]
+    struct Inner {
+        #[serde(rename = "sheetId")]
+        _sheet_id: i32,
+    }
+    #[derive(serde::Deserialize, Debug)]
+    struct Outer {
+        _sheets: Vec<Inner>,
+    }
+
+    let err = deserialize::<Outer>(br#"{"_sheets": [{"sheetId": 1}, {"sheetId": "x"}]}"#).unwrap_err();
+    match err {
+        Error::Decode { path, .. } => assert_eq!(path, "_sheets[1].sheetId"),
+        e => panic!("unexpected error {}", e),
+    }
+}
+//</editor-fold>
@@ -1,54 +1,48 @@
-use std::error::Error;
-
-use curl::easy::Easy;
 use serde::{Deserialize, Serialize};
+#[cfg(test)]
 use serde_json::json;
-use std::borrow::Borrow;
 
-pub const SERVICE_ENDPOINT: &str = "https://sheets.googleapis.com/v4/spreadsheets";
+use crate::auth::SPREADSHEETS_SCOPE;
+use crate::call::{encode_segment, Call};
+use crate::client::{Client, Version};
+use crate::error::Result;
+use crate::transport::{Method, Request};
+#[cfg(test)]
+use crate::error::Error;
 
-pub struct GSheetApi {}
+pub struct GSheetApi {
+    client: Client,
+}
 
 impl GSheetApi {
+    /// An API handle sending no credentials, usable with public spreadsheets only.
     pub fn new() -> Self {
-        Self {}
+        Self::with_client(Client::new(Version::V4))
     }
 
-    pub fn get(&self, sheet_id: &str) -> Result<Spreadsheet, Box<dyn Error>> {
-        let mut client = Easy::new();
-        client.url(&*format!("{}/{}", SERVICE_ENDPOINT, sheet_id))?;
-
-        let mut dst = Vec::new();
-
-        {
-            let mut transfer = client.transfer();
-            transfer.write_function(|data| {
-                dst.extend_from_slice(data);
-                Ok(data.len())
-            })?;
-        }
-
-        client.perform()?;
-
-        match client.response_code()? {
-          status if status == 200 => {
-            let spreadsheet: Spreadsheet = deserialize(&dst).expect("couldn't deserialize");
+    /// An API handle sending every request through `client`.
+    pub fn with_client(client: Client) -> Self {
+        Self { client }
+    }
 
-            Ok(spreadsheet)
-          },
-          status => {
-            panic!("status code is {}", status)
-          },
-        }
+    pub fn get(&self, sheet_id: &str) -> Result<Spreadsheet> {
+        self.client.execute(get(sheet_id))
     }
 }
 
-fn deserialize<'a, T: Deserialize<'a>>(dst: &'a [u8]) -> Result<T, Box<dyn Error + '_>> {
-    let data: T = serde_json::from_slice(&dst)?;
-
-    Ok(data)
+/// `spreadsheets.get`, shared with the async API.
+pub(crate) fn get(sheet_id: &str) -> Call<Spreadsheet> {
+    Call::new(
+        Request::new(Method::Get, format!("spreadsheets/{}", encode_segment(sheet_id))),
+        &[SPREADSHEETS_SCOPE],
+    )
 }
 
+impl Default for GSheetApi {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 #[derive(Deserialize, Serialize)]
 struct Format {}
@@ -87,16 +81,65 @@ pub struct Spreadsheet {
 //<editor-fold desc="Tests">
 
 #[test]
+#[ignore = "requires network access to sheets.googleapis.com"]
 fn test_spreadsheet_request() {
   let api = GSheetApi::new();
-  match api.get("1Zzq6vF93SJsd1iNnLdxtA2Yg3SP115Mtli4UYGeJD4A") {
-    Ok(spreadsheet) => {
-
-    },
-    Err(e) => {assert_eq!(false, true)},
+  if let Err(e) = api.get("1Zzq6vF93SJsd1iNnLdxtA2Yg3SP115Mtli4UYGeJD4A") {
+    panic!("{}", e);
   }
 }
 
+#[test]
+fn test_spreadsheet_request_mock() {
+    use crate::transport::Response;
+
+    let transport = |request: &Request| -> Result<Response> {
+        assert_eq!(request.method, Method::Get);
+        assert_eq!(request.url, "https://sheets.googleapis.com/v4/spreadsheets/id");
+
+        Ok(Response {
+            status: 404,
+            headers: vec![],
+            body: br#"{"error":{"code":404,"message":"Requested entity was not found.","status":"NOT_FOUND"}}"#.to_vec(),
+        })
+    };
+    let api = Client::new(Version::V4).with_transport(transport).gsheet();
+
+    match api.get("id") {
+        Err(Error::Http { status, error }) => {
+            assert_eq!(status, 404);
+            assert_eq!(error.status, "NOT_FOUND");
+        },
+        _ => panic!("expected a 404"),
+    }
+}
+
+#[test]
+fn test_get_encodes_id() {
+    assert_eq!(get("a/b?c#d").request.url, "spreadsheets/a%2Fb%3Fc%23d");
+}
+
+#[test]
+fn test_transport_error_redacts_api_key() {
+    use crate::error::{TransportError, TransportErrorKind};
+    use crate::transport::Response;
+
+    let transport = |request: &Request| -> Result<Response> {
+        let message = format!("error sending request for url ({})", request.url);
+
+        Err(TransportError::new(TransportErrorKind::Other, message).into())
+    };
+    let api = Client::new(Version::V4).with_api_key("AIzaSecret").with_transport(transport).gsheet();
+
+    let err = match api.get("id") {
+        Err(err @ Error::Transport(_)) => err,
+        _ => panic!("expected a transport error"),
+    };
+    assert!(!err.to_string().contains("AIzaSecret"));
+    assert!(err.to_string().contains("key=<redacted>"));
+    assert!(!format!("{:?}", err).contains("AIzaSecret"));
+}
+
 #[test]
 fn test_spreadsheet_decode() {
     let json_value = json!({
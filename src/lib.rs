@@ -1,2 +1,15 @@
+pub mod auth;
+mod call;
 pub mod client;
+mod date;
+pub mod endpoint;
+pub mod error;
+pub mod gsheet;
+#[cfg(feature = "async")]
+pub mod nonblocking;
+pub mod rate_limit;
+pub mod retry;
 pub mod sheet;
+pub mod transport;
+#[cfg(test)]
+mod test_server;
@@ -0,0 +1,60 @@
+use super::Client;
+use crate::client::Version;
+use crate::error::Result;
+use crate::gsheet::{self, Spreadsheet};
+
+/// The async counterpart of `gsheet::GSheetApi`.
+pub struct GSheetApi {
+    client: Client,
+}
+
+impl GSheetApi {
+    /// An API handle sending no credentials, usable with public spreadsheets only.
+    pub fn new() -> Self {
+        Self::with_client(Client::new(Version::V4))
+    }
+
+    /// An API handle sending every request through `client`.
+    pub fn with_client(client: Client) -> Self {
+        Self { client }
+    }
+
+    pub async fn get(&self, sheet_id: &str) -> Result<Spreadsheet> {
+        self.client.execute(gsheet::get(sheet_id)).await
+    }
+}
+
+impl Default for GSheetApi {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+//<editor-fold desc="Tests">
+
+#[tokio::test]
+async fn test_spreadsheet_request_mock() {
+    use crate::error::Error;
+    use crate::transport::{BlockingTransport, Request, Response};
+
+    let transport = |request: &Request| -> Result<Response> {
+        assert!(request.url.ends_with("/id?key=secret"));
+
+        Ok(Response {
+            status: 400,
+            headers: vec![],
+            body: br#"{"error":{"code":400,"message":"API key not valid: secret","status":"INVALID_ARGUMENT"}}"#.to_vec(),
+        })
+    };
+    let client = crate::client::Client::new(Version::V4).with_api_key("secret");
+    let api = Client::from(client).with_async_transport(BlockingTransport::new(transport)).gsheet();
+
+    match api.get("id").await {
+        Err(Error::Http { status, error }) => {
+            assert_eq!(status, 400);
+            assert_eq!(error.message, "API key not valid: <redacted>");
+        },
+        _ => panic!("expected a 400"),
+    }
+}
+//</editor-fold>
@@ -0,0 +1,163 @@
+//! The async API, enabled by the `async` feature.
+//!
+//! It builds the same requests and decodes the same models as the blocking API, only sending
+//! them through an `AsyncTransport`. Configure credentials on a blocking `client::Client` and
+//! convert it: `nonblocking::Client::from(client)`.
+
+mod gsheet;
+mod sheet;
+
+use std::sync::Arc;
+
+pub use self::gsheet::GSheetApi;
+pub use self::sheet::SheetApi;
+
+use crate::auth::Auth;
+use crate::call::Call;
+use crate::client::{self, Version};
+use crate::error::Result;
+use crate::rate_limit::RateLimiter;
+use crate::transport::{spawn_blocking, AsyncTransport, BlockingTransport, Request, Response};
+
+/// The async client. Cloning it is cheap, clones share credentials, token cache, transports and rate limiter.
+#[derive(Clone)]
+pub struct Client {
+    inner: client::Client,
+    transport: Arc<dyn AsyncTransport>,
+}
+
+impl Client {
+    pub fn new(version: Version) -> Self {
+        Self::from(client::Client::new(version))
+    }
+
+    /// Sends API calls through `transport`. Tokens are still fetched through the blocking client's transport.
+    pub fn with_async_transport<T: AsyncTransport + 'static>(mut self, transport: T) -> Self {
+        self.transport = Arc::new(transport);
+        self
+    }
+
+    pub fn version(&self) -> &Version {
+        self.inner.version()
+    }
+
+    /// The rate limiter, to monitor its bucket levels.
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.inner.rate_limiter()
+    }
+
+    /// An API handle sharing this client's settings, connections and token cache.
+    pub fn sheet(&self) -> SheetApi {
+        SheetApi::with_client(self.clone())
+    }
+
+    pub fn gsheet(&self) -> GSheetApi {
+        GSheetApi::with_client(self.clone())
+    }
+
+    /// Authenticates and sends `call`, within the rate limits and retrying as the policy allows,
+    /// then decodes its response.
+    pub(crate) async fn execute<T>(&self, call: Call<T>) -> Result<T> {
+        let Call { mut request, scopes, idempotent, operation, decode, error } = call;
+        if let Some(error) = error {
+            return Err(error);
+        }
+        request.url = self.inner.url(&request.url);
+
+        let mut attempt = 1;
+        loop {
+            if let Some(limiter) = &self.inner.limiter {
+                tokio::time::sleep(limiter.reserve(operation)).await;
+            }
+            let result = self.send(request.clone(), scopes).await;
+
+            match self.inner.retry.delay(attempt, idempotent, &result) {
+                Some(delay) => tokio::time::sleep(delay).await,
+                None => {
+                    return result
+                        .and_then(|response| decode(&response))
+                        .map_err(|e| self.inner.auth.redact(e))
+                }
+            }
+            attempt += 1;
+        }
+    }
+
+    /// Authenticates and sends `request`, once more with a new token if the server rejected the cached one.
+    async fn send(&self, request: Request, scopes: &'static [&'static str]) -> Result<Response> {
+        let authorized = self.authorize(request.clone(), scopes).await?;
+        let response = self.transport.send(&authorized).await?;
+
+        if response.status == 401 && self.inner.auth.reject(&authorized, scopes) {
+            let authorized = self.authorize(request, scopes).await?;
+            return self.transport.send(&authorized).await;
+        }
+        Ok(response)
+    }
+
+    async fn authorize(&self, request: Request, scopes: &'static [&'static str]) -> Result<Request> {
+        match &self.inner.auth {
+            // Fetching a token may block on the network, keep it off the runtime threads.
+            Auth::Token(_) => {
+                let inner = self.inner.clone();
+                spawn_blocking(move || inner.authorize(request, scopes)).await
+            }
+            _ => self.inner.authorize(request, scopes),
+        }
+    }
+}
+
+impl From<client::Client> for Client {
+    /// Keeps the settings of `client`, whose transport is used to fetch tokens.
+    ///
+    /// With the `rustls` feature, API calls go through async reqwest configured with the same `HttpOptions`,
+    /// otherwise, or if `client` was given one with `with_transport`, through the transport of `client`
+    /// on the blocking pool.
+    fn from(client: client::Client) -> Self {
+        Self {
+            transport: async_transport(&client),
+            inner: client,
+        }
+    }
+}
+
+fn async_transport(client: &client::Client) -> Arc<dyn AsyncTransport> {
+    // The options were validated when set on `client`.
+    #[cfg(feature = "rustls")]
+    if client.custom_transport {
+        return Arc::new(BlockingTransport::from_arc(client.transport.clone()));
+    }
+    #[cfg(feature = "rustls")]
+    if let Ok(transport) = crate::transport::AsyncReqwestTransport::with_options(&client.http) {
+        return Arc::new(transport);
+    }
+
+    Arc::new(BlockingTransport::from_arc(client.transport.clone()))
+}
+
+//<editor-fold desc="Tests">
+
+#[tokio::test]
+async fn test_from_keeps_custom_transport() {
+    use crate::error::Error;
+
+    let transport = |request: &Request| -> Result<Response> {
+        assert!(request.url.ends_with("/spreadsheets/id"));
+
+        Ok(Response {
+            status: 404,
+            headers: vec![],
+            body: br#"{"error":{"code":404,"message":"Requested entity was not found.","status":"NOT_FOUND"}}"#.to_vec(),
+        })
+    };
+    let client = client::Client::new(Version::V4).with_transport(transport);
+
+    match Client::from(client).sheet().get("id", &Default::default()).await {
+        Err(Error::Http { status, error }) => {
+            assert_eq!(status, 404);
+            assert_eq!(error.message, "Requested entity was not found.");
+        }
+        _ => panic!("expected the 404 of the custom transport"),
+    }
+}
+//</editor-fold>
@@ -0,0 +1,178 @@
+use super::Client;
+use crate::client::Version;
+use crate::error::Result;
+use crate::sheet::models::{
+    AppendValuesResponse, BatchClearValuesByDataFilterResponse, BatchClearValuesResponse,
+    BatchGetValuesByDataFilterResponse, BatchGetValuesResponse, BatchUpdateValuesByDataFilterResponse,
+    BatchUpdateValuesResponse, ClearValuesResponse, SheetProperties, Spreadsheet, UpdateValuesResponse, ValueRange,
+};
+use crate::sheet::values::{
+    self, AppendValuesRequest, BatchClearValuesByDataFilterRequest, BatchClearValuesRequest,
+    BatchGetValuesByDataFilterRequest, BatchGetValuesRequest, BatchUpdateValuesByDataFilterRequest,
+    BatchUpdateValuesRequest, GetValuesRequest, UpdateValuesRequest,
+};
+use crate::sheet::requests::{BatchUpdateSpreadsheetRequest, BatchUpdateSpreadsheetResponse};
+use crate::sheet::{self, GetByDataFilterRequest, GetRequest};
+
+/// The async counterpart of `sheet::SheetApi`.
+pub struct SheetApi {
+    client: Client,
+}
+
+impl SheetApi {
+    pub fn new() -> Self {
+        Self::with_client(Client::new(Version::V4))
+    }
+
+    /// An API handle sending every request through `client`.
+    pub fn with_client(client: Client) -> Self {
+        Self { client }
+    }
+
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// Creates `spreadsheet` and returns it as created, with its ID and url.
+    pub async fn create(&self, spreadsheet: &Spreadsheet) -> Result<Spreadsheet> {
+        self.client.execute(sheet::create(spreadsheet)).await
+    }
+
+    /// Fetches the spreadsheet `spreadsheet_id`, limited to what `request` asks for.
+    pub async fn get(&self, spreadsheet_id: &str, request: &GetRequest) -> Result<Spreadsheet> {
+        self.client.execute(sheet::get(spreadsheet_id, request)).await
+    }
+
+    /// Fetches the spreadsheet `spreadsheet_id`, with the data of the ranges matched by the filters of `request`.
+    pub async fn get_by_data_filter(
+        &self,
+        spreadsheet_id: &str,
+        request: &GetByDataFilterRequest,
+    ) -> Result<Spreadsheet> {
+        self.client.execute(sheet::get_by_data_filter(spreadsheet_id, request)).await
+    }
+
+    /// Applies the changes of `request` to the spreadsheet `spreadsheet_id`, all of them or none if one is invalid.
+    pub async fn batch_update(
+        &self,
+        spreadsheet_id: &str,
+        request: &BatchUpdateSpreadsheetRequest,
+    ) -> Result<BatchUpdateSpreadsheetResponse> {
+        self.client.execute(sheet::batch_update(spreadsheet_id, request)).await
+    }
+
+    /// Copies the sheet `sheet_id` into the spreadsheet `destination_spreadsheet_id`, returning the properties of the copy.
+    pub async fn copy_sheet_to(
+        &self,
+        spreadsheet_id: &str,
+        sheet_id: i32,
+        destination_spreadsheet_id: &str,
+    ) -> Result<SheetProperties> {
+        self.client.execute(sheet::copy_sheet_to(spreadsheet_id, sheet_id, destination_spreadsheet_id)).await
+    }
+
+    /// The values of the spreadsheets, without their formatting.
+    pub fn values(&self) -> ValuesApi<'_> {
+        ValuesApi { client: &self.client }
+    }
+}
+
+impl Default for SheetApi {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The async counterpart of `sheet::values::ValuesApi`.
+pub struct ValuesApi<'a> {
+    client: &'a Client,
+}
+
+impl ValuesApi<'_> {
+    /// Reads the values of `range`, in A1 notation.
+    pub async fn get(&self, spreadsheet_id: &str, range: &str, request: &GetValuesRequest) -> Result<ValueRange> {
+        self.client.execute(values::get(spreadsheet_id, range, request)).await
+    }
+
+    /// Writes `values` to `range`, in A1 notation.
+    pub async fn update(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+        values: &ValueRange,
+        request: &UpdateValuesRequest,
+    ) -> Result<UpdateValuesResponse> {
+        self.client.execute(values::update(spreadsheet_id, range, values, request)).await
+    }
+
+    /// Writes `values` after the last row of the table found in `range`, in A1 notation.
+    pub async fn append(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+        values: &ValueRange,
+        request: &AppendValuesRequest,
+    ) -> Result<AppendValuesResponse> {
+        self.client.execute(values::append(spreadsheet_id, range, values, request)).await
+    }
+
+    /// Clears the values of `range`, in A1 notation, keeping formatting and data validation.
+    pub async fn clear(&self, spreadsheet_id: &str, range: &str) -> Result<ClearValuesResponse> {
+        self.client.execute(values::clear(spreadsheet_id, range)).await
+    }
+
+    /// Reads the values of several ranges in one call, returned in the order of `request.ranges()`.
+    /// Too many ranges to fit in a url are read by data filter instead, with the same response.
+    pub async fn batch_get(
+        &self,
+        spreadsheet_id: &str,
+        request: &BatchGetValuesRequest,
+    ) -> Result<BatchGetValuesResponse> {
+        self.client.execute(values::batch_get(spreadsheet_id, request)).await
+    }
+
+    /// Writes the values of several ranges in one call, responses coming in the order of `request.data()`.
+    pub async fn batch_update(
+        &self,
+        spreadsheet_id: &str,
+        request: &BatchUpdateValuesRequest,
+    ) -> Result<BatchUpdateValuesResponse> {
+        self.client.execute(values::batch_update(spreadsheet_id, request)).await
+    }
+
+    /// Clears several ranges in one call, returned in the order of `request.ranges()`.
+    pub async fn batch_clear(
+        &self,
+        spreadsheet_id: &str,
+        request: &BatchClearValuesRequest,
+    ) -> Result<BatchClearValuesResponse> {
+        self.client.execute(values::batch_clear(spreadsheet_id, request)).await
+    }
+
+    /// Reads the values of the ranges matched by the filters of `request`.
+    pub async fn batch_get_by_data_filter(
+        &self,
+        spreadsheet_id: &str,
+        request: &BatchGetValuesByDataFilterRequest,
+    ) -> Result<BatchGetValuesByDataFilterResponse> {
+        self.client.execute(values::batch_get_by_data_filter(spreadsheet_id, request)).await
+    }
+
+    /// Writes values to the ranges matched by the filters of `request`, responses coming in the order of `request.data()`.
+    pub async fn batch_update_by_data_filter(
+        &self,
+        spreadsheet_id: &str,
+        request: &BatchUpdateValuesByDataFilterRequest,
+    ) -> Result<BatchUpdateValuesByDataFilterResponse> {
+        self.client.execute(values::batch_update_by_data_filter(spreadsheet_id, request)).await
+    }
+
+    /// Clears the ranges matched by the filters of `request`.
+    pub async fn batch_clear_by_data_filter(
+        &self,
+        spreadsheet_id: &str,
+        request: &BatchClearValuesByDataFilterRequest,
+    ) -> Result<BatchClearValuesByDataFilterResponse> {
+        self.client.execute(values::batch_clear_by_data_filter(spreadsheet_id, request)).await
+    }
+}
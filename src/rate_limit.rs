@@ -0,0 +1,134 @@
+//! Client-side rate limiting, keeping calls within the Sheets API per-minute quotas.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Google's default quota of read and of write requests per minute, per user per project.
+pub const DEFAULT_REQUESTS_PER_MINUTE: u32 = 60;
+
+/// Which quota a call counts against.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operation {
+    Read,
+    Write,
+}
+
+/// Token buckets for read and write calls, refilled continuously up to a minute's worth of quota.
+///
+/// A call takes one token from its bucket, waiting for it when the bucket is empty rather than failing.
+#[derive(Debug)]
+pub struct RateLimiter {
+    read: Bucket,
+    write: Bucket,
+}
+
+impl RateLimiter {
+    /// Google's default quotas: 60 reads and 60 writes per minute.
+    pub fn new() -> Self {
+        Self::with_limits(DEFAULT_REQUESTS_PER_MINUTE, DEFAULT_REQUESTS_PER_MINUTE)
+    }
+
+    /// Custom quotas, e.g. those granted to your project in the Cloud console.
+    pub fn with_limits(reads_per_minute: u32, writes_per_minute: u32) -> Self {
+        Self {
+            read: Bucket::new(reads_per_minute),
+            write: Bucket::new(writes_per_minute),
+        }
+    }
+
+    /// Tokens currently in the bucket of `operation`, negative while calls are waiting for one.
+    pub fn level(&self, operation: Operation) -> f64 {
+        self.bucket(operation).level()
+    }
+
+    /// Takes a token for `operation` and returns how long to wait before it becomes valid.
+    pub(crate) fn reserve(&self, operation: Operation) -> Duration {
+        self.bucket(operation).reserve()
+    }
+
+    fn bucket(&self, operation: Operation) -> &Bucket {
+        match operation {
+            Operation::Read => &self.read,
+            Operation::Write => &self.write,
+        }
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug)]
+struct Bucket {
+    capacity: f64,
+    per_second: f64,
+    state: Mutex<BucketState>,
+}
+
+#[derive(Debug)]
+struct BucketState {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl Bucket {
+    fn new(per_minute: u32) -> Self {
+        let capacity = f64::from(per_minute.max(1));
+
+        Self {
+            capacity,
+            per_second: capacity / 60.0,
+            state: Mutex::new(BucketState {
+                tokens: capacity,
+                updated_at: Instant::now(),
+            }),
+        }
+    }
+
+    fn level(&self) -> f64 {
+        self.refilled().tokens
+    }
+
+    fn reserve(&self) -> Duration {
+        let mut state = self.refilled();
+        state.tokens -= 1.0;
+
+        if state.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-state.tokens / self.per_second)
+        }
+    }
+
+    fn refilled(&self) -> std::sync::MutexGuard<'_, BucketState> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        let elapsed = now.duration_since(state.updated_at).as_secs_f64();
+
+        state.tokens = (state.tokens + elapsed * self.per_second).min(self.capacity);
+        state.updated_at = now;
+        state
+    }
+}
+
+//<editor-fold desc="Tests">
+
+#[test]
+fn test_rate_limiter() {
+    let limiter = RateLimiter::with_limits(2, 60);
+
+    assert_eq!(limiter.reserve(Operation::Read), Duration::ZERO);
+    assert_eq!(limiter.reserve(Operation::Read), Duration::ZERO);
+    // Empty: the next read token comes in 30s, the one after in 60s.
+    let wait = limiter.reserve(Operation::Read);
+    assert!(wait > Duration::from_secs(29) && wait <= Duration::from_secs(30));
+    assert!(limiter.reserve(Operation::Read) > Duration::from_secs(59));
+    assert!(limiter.level(Operation::Read) < -1.9);
+
+    // Writes have their own bucket.
+    assert_eq!(limiter.reserve(Operation::Write), Duration::ZERO);
+    assert!(limiter.level(Operation::Write) > 58.9);
+}
+//</editor-fold>
@@ -0,0 +1,243 @@
+//! Retrying failed calls with exponential backoff.
+
+use std::time::{Duration, SystemTime};
+
+use rand::Rng;
+
+use crate::date::parse_http_date;
+use crate::error::{ApiError, Error, Result};
+use crate::transport::Response;
+
+const DEFAULT_MAX_ATTEMPTS: u32 = 5;
+const DEFAULT_BASE_DELAY: Duration = Duration::from_secs(1);
+const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(32);
+const DEFAULT_MAX_SERVER_DELAY: Duration = Duration::from_secs(60);
+const DEFAULT_JITTER: f64 = 0.5;
+
+/// When and how long to wait before sending a failed call again.
+///
+/// Calls are retried on 408, 429 and 5xx statuses and on connect and timeout errors. The wait doubles
+/// after each attempt, from the base delay up to the max delay, unless the server asks for a specific
+/// one through `Retry-After` or `RetryInfo`. A server asking for longer than the max server delay
+/// (60s by default) gets its error returned instead of a wait. Non-idempotent writes, e.g. appending
+/// values, are only retried when they are known to be safe or `with_non_idempotent` is set.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    max_server_delay: Duration,
+    jitter: f64,
+    non_idempotent: bool,
+}
+
+impl RetryPolicy {
+    /// 5 attempts, waiting 1s, 2s, 4s and 8s (minus up to 50% jitter) in between.
+    pub fn new() -> Self {
+        Self {
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            base_delay: DEFAULT_BASE_DELAY,
+            max_delay: DEFAULT_MAX_DELAY,
+            max_server_delay: DEFAULT_MAX_SERVER_DELAY,
+            jitter: DEFAULT_JITTER,
+            non_idempotent: false,
+        }
+    }
+
+    /// Sends every call once.
+    pub fn none() -> Self {
+        Self::new().with_max_attempts(1)
+    }
+
+    /// How many times a call is sent at most, the first attempt included.
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// The wait after the first attempt.
+    pub fn with_base_delay(mut self, delay: Duration) -> Self {
+        self.base_delay = delay;
+        self
+    }
+
+    /// The longest backoff between two attempts. Server-requested delays are bound by `with_max_server_delay`.
+    pub fn with_max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// The longest wait honored when the server asks for one, beyond which the call fails right away.
+    pub fn with_max_server_delay(mut self, delay: Duration) -> Self {
+        self.max_server_delay = delay;
+        self
+    }
+
+    /// Shortens each backoff by a random fraction of up to `jitter`, between 0 and 1.
+    pub fn with_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Retries non-idempotent writes too, at the risk of applying them twice.
+    pub fn with_non_idempotent(mut self, retry: bool) -> Self {
+        self.non_idempotent = retry;
+        self
+    }
+
+    /// How long to wait before another attempt, `None` if `result` of attempt number `attempt` is final.
+    pub(crate) fn delay(&self, attempt: u32, idempotent: bool, result: &Result<Response>) -> Option<Duration> {
+        if attempt >= self.max_attempts || !(idempotent || self.non_idempotent) {
+            return None;
+        }
+
+        match result {
+            Ok(response) if is_retryable(response.status) => match server_delay(response) {
+                Some(delay) if delay > self.max_server_delay => None,
+                Some(delay) => Some(delay),
+                None => Some(self.backoff(attempt)),
+            },
+            Err(Error::Transport(e)) if e.is_transient() => Some(self.backoff(attempt)),
+            _ => None,
+        }
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        let delay = self
+            .base_delay
+            .checked_mul(2u32.saturating_pow(attempt - 1))
+            .map_or(self.max_delay, |delay| delay.min(self.max_delay));
+
+        delay.mul_f64(1.0 - rand::thread_rng().gen_range(0.0..=self.jitter))
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn is_retryable(status: u32) -> bool {
+    matches!(status, 408 | 429 | 500..=599)
+}
+
+/// The delay asked for by a `Retry-After` header (in seconds or until an HTTP date) or a `RetryInfo` error detail.
+fn server_delay(response: &Response) -> Option<Duration> {
+    response
+        .header("Retry-After")
+        .and_then(|value| retry_after(value.trim()))
+        .or_else(|| ApiError::from_body(response.status, &response.body).retry_delay())
+}
+
+/// A `Retry-After` value, a date in the past meaning right away.
+fn retry_after(value: &str) -> Option<Duration> {
+    if let Ok(seconds) = value.parse() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = parse_http_date(value)?;
+    Some(date.duration_since(SystemTime::now()).unwrap_or_default())
+}
+
+//<editor-fold desc="Tests">
+
+#[cfg(test)]
+fn response(status: u32, headers: Vec<(String, String)>, body: &str) -> Result<Response> {
+    Ok(Response {
+        status,
+        headers,
+        body: body.as_bytes().to_vec(),
+    })
+}
+
+#[test]
+fn test_retry_delay() {
+    use crate::error::{TransportError, TransportErrorKind};
+
+    let policy = RetryPolicy::new().with_jitter(0.0);
+
+    assert_eq!(policy.delay(1, true, &response(503, vec![], "")), Some(Duration::from_secs(1)));
+    assert_eq!(policy.delay(3, true, &response(429, vec![], "")), Some(Duration::from_secs(4)));
+    assert_eq!(policy.delay(5, true, &response(500, vec![], "")), None);
+    assert_eq!(policy.delay(1, true, &response(404, vec![], "")), None);
+    assert_eq!(policy.delay(1, false, &response(503, vec![], "")), None);
+    assert!(policy.with_non_idempotent(true).delay(1, false, &response(503, vec![], "")).is_some());
+
+    let policy = RetryPolicy::new().with_max_attempts(20).with_jitter(0.0);
+    assert_eq!(policy.delay(10, true, &response(502, vec![], "")), Some(DEFAULT_MAX_DELAY));
+
+    let timeout = Err(Error::from(TransportError::new(TransportErrorKind::Timeout, "timed out")));
+    assert_eq!(policy.delay(2, true, &timeout), Some(Duration::from_secs(2)));
+    let other = Err(Error::from(TransportError::new(TransportErrorKind::Other, "bad url")));
+    assert_eq!(policy.delay(1, true, &other), None);
+
+    let jittered = RetryPolicy::new().delay(2, true, &response(503, vec![], "")).unwrap();
+    assert!(jittered >= Duration::from_secs(1) && jittered <= Duration::from_secs(2));
+}
+
+#[test]
+fn test_retry_server_delay() {
+    let policy = RetryPolicy::new();
+
+    let retry_after = response(429, vec![("retry-after".to_string(), "7".to_string())], "");
+    assert_eq!(policy.delay(1, true, &retry_after), Some(Duration::from_secs(7)));
+
+    let retry_info = response(
+        503,
+        vec![],
+        r#"{"error":{"code":503,"message":"Unavailable","status":"UNAVAILABLE","details":[
+            {"@type":"type.googleapis.com/google.rpc.RetryInfo","retryDelay":"2.5s"}]}}"#,
+    );
+    assert_eq!(policy.delay(1, true, &retry_info), Some(Duration::from_millis(2500)));
+
+    let day = response(429, vec![("Retry-After".to_string(), "86400".to_string())], "");
+    assert_eq!(policy.delay(1, true, &day), None);
+
+    // An HTTP date rather than seconds: a past one means right away, a far one is past the ceiling.
+    let past = response(503, vec![("Retry-After".to_string(), "Sun, 06 Nov 1994 08:49:37 GMT".to_string())], "");
+    assert_eq!(policy.delay(1, true, &past), Some(Duration::ZERO));
+    let far = response(503, vec![("Retry-After".to_string(), "Fri, 01 Jan 2100 00:00:00 GMT".to_string())], "");
+    assert_eq!(policy.delay(1, true, &far), None);
+    let policy = RetryPolicy::new().with_max_server_delay(Duration::from_secs(5));
+    assert_eq!(policy.delay(1, true, &retry_after), None);
+    assert_eq!(policy.delay(1, true, &retry_info), Some(Duration::from_millis(2500)));
+}
+
+#[test]
+fn test_client_retries() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use crate::call::Call;
+    use crate::client::{Client, Version};
+    use crate::transport::{Method, Request};
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let transport = {
+        let calls = calls.clone();
+        move |_: &Request| -> Result<Response> {
+            match calls.fetch_add(1, Ordering::SeqCst) {
+                0 | 1 => response(503, vec![], ""),
+                _ => response(200, vec![], "{}"),
+            }
+        }
+    };
+    let client = Client::new(Version::V4)
+        .with_transport(transport)
+        .with_retry_policy(RetryPolicy::new().with_base_delay(Duration::from_millis(1)));
+
+    let get = Call::<serde_json::Value>::new(Request::new(Method::Get, "spreadsheets"), &[]);
+    assert!(client.execute(get).is_ok());
+    assert_eq!(calls.swap(0, Ordering::SeqCst), 3);
+
+    let post = Call::<serde_json::Value>::new(Request::new(Method::Post, "spreadsheets"), &[]);
+    assert_eq!(client.execute(post).unwrap_err().status(), Some(503));
+    assert_eq!(calls.swap(0, Ordering::SeqCst), 1);
+
+    let mut post = Call::<serde_json::Value>::new(Request::new(Method::Post, "spreadsheets"), &[]);
+    post.idempotent = true;
+    assert!(client.execute(post).is_ok());
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+}
+//</editor-fold>
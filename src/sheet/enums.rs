@@ -1,23 +1,39 @@
+use std::convert::TryFrom;
 use std::fmt::{Display, Formatter};
 use serde::{Deserialize, Serialize};
 
+use crate::error::{Error, Result};
+
+/// The error of converting an `Unknown` value of `name` back to text.
+fn unknown(name: &str) -> Error {
+    Error::Encode(format!("{}::Unknown holds a value unknown to this version of the library", name))
+}
+
 //region Dimension
 /// The metadata visibility.
-#[derive(Serialize, Deserialize)]
-#[serde(untagged)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum Dimension {
     DimensionUnspecified,
     Rows,
     Columns,
+    /// A value unknown to this version of the library, e.g. one added to the API since.
+    /// It's only ever read: sending it back fails rather than sending a value the API rejects.
+    #[serde(other, skip_serializing)]
+    Unknown,
 }
 
-impl Into<&str> for Dimension {
-    fn into(self) -> &'static str {
-        match self {
-            Self::Rows => "ROWS",
-            Self::Columns => "COLUMNS",
-            Self::DimensionUnspecified => "DIMENSION_UNSPECIFIED",
-        }
+impl TryFrom<Dimension> for &str {
+    type Error = Error;
+
+    /// Fails for `Dimension::Unknown`, whose value isn't known.
+    fn try_from(value: Dimension) -> Result<Self> {
+        Ok(match value {
+            Dimension::Rows => "ROWS",
+            Dimension::Columns => "COLUMNS",
+            Dimension::DimensionUnspecified => "DIMENSION_UNSPECIFIED",
+            Dimension::Unknown => return Err(unknown("Dimension")),
+        })
     }
 }
 
@@ -26,7 +42,8 @@ impl From<&str> for Dimension {
         match text {
             "ROWS" => Self::Rows,
             "COLUMNS" => Self::Columns,
-            _ => Self::DimensionUnspecified,
+            "DIMENSION_UNSPECIFIED" => Self::DimensionUnspecified,
+            _ => Self::Unknown,
         }
     }
 }
@@ -36,6 +53,7 @@ impl Display for Dimension {
         match self {
             Self::Rows => write!(f, "Operates on the rows of a sheet."),
             Self::Columns => write!(f, "Operates on the columns of a sheet."),
+            Self::Unknown => write!(f, "A value unknown to this version of the library."),
             _ => write!(f, "The default value, do not use."),
         }
     }
@@ -44,12 +62,16 @@ impl Display for Dimension {
 
 //region Visibility
 /// The metadata visibility.
-#[derive(Serialize, Deserialize)]
-#[serde(untagged)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum Visibility {
     DeveloperMetadataVisibilityUnspecified,
     Document,
     Project,
+    /// A value unknown to this version of the library, e.g. one added to the API since.
+    /// It's only ever read: sending it back fails rather than sending a value the API rejects.
+    #[serde(other, skip_serializing)]
+    Unknown,
 }
 
 impl Display for Visibility {
@@ -57,18 +79,23 @@ impl Display for Visibility {
         match self {
             Self::Document => write!(f, "DOCUMENT"),
             Self::Project => write!(f, "PROJECT"),
+            Self::Unknown => write!(f, "UNKNOWN"),
             _ => write!(f, "DEVELOPER_METADATA_VISIBILITY_UNSPECIFIED"),
         }
     }
 }
 
-impl Into<&str> for Visibility {
-    fn into(self) -> &'static str {
-        match self {
-            Self::Document => "DOCUMENT",
-            Self::Project => "PROJECT",
+impl TryFrom<Visibility> for &str {
+    type Error = Error;
+
+    /// Fails for `Visibility::Unknown`, whose value isn't known.
+    fn try_from(value: Visibility) -> Result<Self> {
+        Ok(match value {
+            Visibility::Document => "DOCUMENT",
+            Visibility::Project => "PROJECT",
+            Visibility::Unknown => return Err(unknown("Visibility")),
             _ => "DEVELOPER_METADATA_VISIBILITY_UNSPECIFIED",
-        }
+        })
     }
 }
 
@@ -77,7 +104,8 @@ impl From<&str> for Visibility {
         match text {
             "DOCUMENT" => Self::Document,
             "PROJECT" => Self::Project,
-            _ => Self::DeveloperMetadataVisibilityUnspecified,
+            "DEVELOPER_METADATA_VISIBILITY_UNSPECIFIED" => Self::DeveloperMetadataVisibilityUnspecified,
+            _ => Self::Unknown,
         }
     }
 }
@@ -85,25 +113,34 @@ impl From<&str> for Visibility {
 
 //region LocationType
 /// The type of location
-#[derive(Serialize, Deserialize)]
-#[serde(untagged)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum LocationType {
     Row,
     Column,
     Sheet,
+    #[serde(rename = "SPREADSHEET")]
     SpreadSheet,
     DeveloperMetadataLocationTypeUnspecified,
+    /// A value unknown to this version of the library, e.g. one added to the API since.
+    /// It's only ever read: sending it back fails rather than sending a value the API rejects.
+    #[serde(other, skip_serializing)]
+    Unknown,
 }
 
-impl Into<&str> for LocationType {
-    fn into(self) -> &'static str {
-        match self {
-            Self::Row => "ROW",
-            Self::Column => "COLUMN",
-            Self::Sheet => "SHEET",
-            Self::SpreadSheet => "SPREADSHEET",
-            Self::DeveloperMetadataLocationTypeUnspecified => "DEVELOPER_METADATA_LOCATION_TYPE_UNSPECIFIED",
-        }
+impl TryFrom<LocationType> for &str {
+    type Error = Error;
+
+    /// Fails for `LocationType::Unknown`, whose value isn't known.
+    fn try_from(value: LocationType) -> Result<Self> {
+        Ok(match value {
+            LocationType::Row => "ROW",
+            LocationType::Column => "COLUMN",
+            LocationType::Sheet => "SHEET",
+            LocationType::SpreadSheet => "SPREADSHEET",
+            LocationType::DeveloperMetadataLocationTypeUnspecified => "DEVELOPER_METADATA_LOCATION_TYPE_UNSPECIFIED",
+            LocationType::Unknown => return Err(unknown("LocationType")),
+        })
     }
 }
 
@@ -114,7 +151,8 @@ impl From<&str> for LocationType {
             "COLUMN" => Self::Column,
             "SHEET" => Self::Sheet,
             "SPREADSHEET" => Self::SpreadSheet,
-            _ => Self::DeveloperMetadataLocationTypeUnspecified,
+            "DEVELOPER_METADATA_LOCATION_TYPE_UNSPECIFIED" => Self::DeveloperMetadataLocationTypeUnspecified,
+            _ => Self::Unknown,
         }
     }
 }
@@ -126,6 +164,7 @@ impl Display for LocationType {
             Self::Column => write!(f, "Developer metadata associated on an entire column dimension."),
             Self::Sheet => write!(f, "Developer metadata associated on an entire sheet."),
             Self::SpreadSheet => write!(f, "Developer metadata associated on the entire spreadsheet."),
+            Self::Unknown => write!(f, "A value unknown to this version of the library."),
             _ => write!(f, "Default value."),
         }
     }
@@ -133,13 +172,17 @@ impl Display for LocationType {
 //endregion
 
 //region RefreshScope
-#[derive(Serialize, Deserialize)]
-#[serde(untagged)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum RefreshScope {
     /// Default value, do not use.
     DataSourceRefreshScopeUnspecified,
     /// Refreshes all data sources and their associated data source objects in the spreadsheet.
     AllDataSources,
+    /// A value unknown to this version of the library, e.g. one added to the API since.
+    /// It's only ever read: sending it back fails rather than sending a value the API rejects.
+    #[serde(other, skip_serializing)]
+    Unknown,
 }
 
 impl Display for RefreshScope {
@@ -147,6 +190,7 @@ impl Display for RefreshScope {
         match self {
             Self::DataSourceRefreshScopeUnspecified => write!(f, "DATA_SOURCE_REFRESH_SCOPE_UNSPECIFIED"),
             Self::AllDataSources => write!(f, "ALL_DATA_SOURCES"),
+            Self::Unknown => write!(f, "UNKNOWN"),
         }
     }
 }
@@ -155,7 +199,8 @@ impl From<&str> for RefreshScope {
     fn from(text: &str) -> Self {
         match text {
             "ALL_DATA_SOURCES" => RefreshScope::AllDataSources,
-            _ => RefreshScope::DataSourceRefreshScopeUnspecified,
+            "DATA_SOURCE_REFRESH_SCOPE_UNSPECIFIED" => RefreshScope::DataSourceRefreshScopeUnspecified,
+            _ => RefreshScope::Unknown,
         }
     }
 }
@@ -164,8 +209,8 @@ impl From<&str> for RefreshScope {
 //region DayOfWeek
 /// Days of the week to refresh.
 /// At least one day must be specified.
-#[derive(Serialize, Deserialize)]
-#[serde(untagged)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum DayOfWeek {
     DayOfWeekUnspecified,
     Monday,
@@ -175,19 +220,24 @@ pub enum DayOfWeek {
     Friday,
     Saturday,
     Sunday,
+    /// A value unknown to this version of the library, e.g. one added to the API since.
+    /// It's only ever read: sending it back fails rather than sending a value the API rejects.
+    #[serde(other, skip_serializing)]
+    Unknown,
 }
 
 impl From<&str> for DayOfWeek {
     fn from(text: &str) -> Self {
         match text {
-            "Monday" => Self::Monday,
-            "Tuesday" => Self::Thursday,
-            "Wednesday" => Self::Wednesday,
-            "Thursday" => Self::Thursday,
-            "Friday" => Self::Friday,
-            "Saturday" => Self::Saturday,
-            "Sunday" => Self::Sunday,
-            _ => Self::DayOfWeekUnspecified,
+            "MONDAY" => Self::Monday,
+            "TUESDAY" => Self::Tuesday,
+            "WEDNESDAY" => Self::Wednesday,
+            "THURSDAY" => Self::Thursday,
+            "FRIDAY" => Self::Friday,
+            "SATURDAY" => Self::Saturday,
+            "SUNDAY" => Self::Sunday,
+            "DAY_OF_WEEK_UNSPECIFIED" => Self::DayOfWeekUnspecified,
+            _ => Self::Unknown,
         }
     }
 }
@@ -202,6 +252,7 @@ impl Display for DayOfWeek {
             Self::Friday => write!(f, "Friday"),
             Self::Saturday => write!(f, "Saturday"),
             Self::Sunday => write!(f, "Sunday"),
+            Self::Unknown => write!(f, "A value unknown to this version of the library."),
             _ => write!(f, "The day of the week is unspecified."),
         }
     }
@@ -209,24 +260,32 @@ impl Display for DayOfWeek {
 //endregion
 
 //region HorizontalAlignment
-/// The horizontal alignment of title in the slicer.
-#[derive(Serialize, Deserialize)]
-#[serde(untagged)]
+/// The horizontal alignment of text in a cell or of the title of a slicer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum HorizontalAlignment {
     HorizontalAlignUnspecified,
     Left,
     Center,
     Right,
+    /// A value unknown to this version of the library, e.g. one added to the API since.
+    /// It's only ever read: sending it back fails rather than sending a value the API rejects.
+    #[serde(other, skip_serializing)]
+    Unknown,
 }
 
-impl Into<&str> for HorizontalAlignment {
-    fn into(self) -> &'static str {
-        match self {
-            Self::Left => "LEFT",
-            Self::Center => "CENTER",
-            Self::Right => "RIGHT",
-            Self::HorizontalAlignUnspecified => "HORIZONTAL_ALIGN_UNSPECIFIED",
-        }
+impl TryFrom<HorizontalAlignment> for &str {
+    type Error = Error;
+
+    /// Fails for `HorizontalAlignment::Unknown`, whose value isn't known.
+    fn try_from(value: HorizontalAlignment) -> Result<Self> {
+        Ok(match value {
+            HorizontalAlignment::Left => "LEFT",
+            HorizontalAlignment::Center => "CENTER",
+            HorizontalAlignment::Right => "RIGHT",
+            HorizontalAlignment::HorizontalAlignUnspecified => "HORIZONTAL_ALIGN_UNSPECIFIED",
+            HorizontalAlignment::Unknown => return Err(unknown("HorizontalAlignment")),
+        })
     }
 }
 
@@ -237,42 +296,57 @@ impl Display for HorizontalAlignment {
             Self::Center => write!(f, "The text is explicitly aligned to the center of the cell."),
             Self::Left => write!(f, "The text is explicitly aligned to the left of the cell."),
             Self::HorizontalAlignUnspecified => write!(f, "The horizontal alignment is not specified. Do not use this."),
+            Self::Unknown => write!(f, "A value unknown to this version of the library."),
         }
     }
 }
 //endregion
 
 //region ThemeColor
-#[derive(Serialize, Deserialize)]
-#[serde(untagged)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ThemeColor {
+    #[serde(rename = "THEME_COLOR_TYPE_UNSPECIFIED")]
     ThemColorTypeUnspecified,
     Text,
     Background,
+    #[serde(rename = "ACCENT1")]
     ACCENT1,
+    #[serde(rename = "ACCENT2")]
     ACCENT2,
+    #[serde(rename = "ACCENT3")]
     ACCENT3,
+    #[serde(rename = "ACCENT4")]
     ACCENT4,
+    #[serde(rename = "ACCENT5")]
     ACCENT5,
+    #[serde(rename = "ACCENT6")]
     ACCENT6,
     Link,
+    /// A value unknown to this version of the library, e.g. one added to the API since.
+    /// It's only ever read: sending it back fails rather than sending a value the API rejects.
+    #[serde(other, skip_serializing)]
+    Unknown,
 }
 
-impl Into<&str> for ThemeColor {
+impl TryFrom<ThemeColor> for &str {
+    type Error = Error;
 
-    fn into(self) -> &'static str {
-        match self {
-            Self::ThemColorTypeUnspecified => "THEME_COLOR_TYPE_UNSPECIFIED",
-            Self::Text => "TEXT",
-            Self::Background => "BACKGROUND",
-            Self::ACCENT1 => "ACCENT1",
-            Self::ACCENT2 => "ACCENT2",
-            Self::ACCENT3 => "ACCENT3",
-            Self::ACCENT4 => "ACCENT4",
-            Self::ACCENT5 => "ACCENT5",
-            Self::ACCENT6 => "ACCENT6",
-            Self::Link => "LINK",
-        }
+    /// Fails for `ThemeColor::Unknown`, whose value isn't known.
+    fn try_from(value: ThemeColor) -> Result<Self> {
+        Ok(match value {
+            ThemeColor::ThemColorTypeUnspecified => "THEME_COLOR_TYPE_UNSPECIFIED",
+            ThemeColor::Text => "TEXT",
+            ThemeColor::Background => "BACKGROUND",
+            ThemeColor::ACCENT1 => "ACCENT1",
+            ThemeColor::ACCENT2 => "ACCENT2",
+            ThemeColor::ACCENT3 => "ACCENT3",
+            ThemeColor::ACCENT4 => "ACCENT4",
+            ThemeColor::ACCENT5 => "ACCENT5",
+            ThemeColor::ACCENT6 => "ACCENT6",
+            ThemeColor::Link => "LINK",
+            ThemeColor::Unknown => return Err(unknown("ThemeColor")),
+        })
     }
 }
 
@@ -289,7 +363,1178 @@ impl Display for ThemeColor {
             Self::ACCENT5 => write!(f, "Represents the fifth accent color"),
             Self::ACCENT6 => write!(f, "Represents the sixth accent color"),
             Self::Link => write!(f, "Represents the color to use for hyperlinks"),
+            Self::Unknown => write!(f, "A value unknown to this version of the library."),
+        }
+    }
+}
+//endregion
+//region SheetType
+/// The kind of sheet.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SheetType {
+    SheetTypeUnspecified,
+    Grid,
+    Object,
+    DataSource,
+    /// A value unknown to this version of the library, e.g. one added to the API since.
+    /// It's only ever read: sending it back fails rather than sending a value the API rejects.
+    #[serde(other, skip_serializing)]
+    Unknown,
+}
+
+impl TryFrom<SheetType> for &str {
+    type Error = Error;
+
+    /// Fails for `SheetType::Unknown`, whose value isn't known.
+    fn try_from(value: SheetType) -> Result<Self> {
+        Ok(match value {
+            SheetType::SheetTypeUnspecified => "SHEET_TYPE_UNSPECIFIED",
+            SheetType::Grid => "GRID",
+            SheetType::Object => "OBJECT",
+            SheetType::DataSource => "DATA_SOURCE",
+            SheetType::Unknown => return Err(unknown("SheetType")),
+        })
+    }
+}
+
+impl From<&str> for SheetType {
+    fn from(text: &str) -> Self {
+        match text {
+            "GRID" => Self::Grid,
+            "OBJECT" => Self::Object,
+            "DATA_SOURCE" => Self::DataSource,
+            "SHEET_TYPE_UNSPECIFIED" => Self::SheetTypeUnspecified,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl Display for SheetType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Grid => write!(f, "The sheet is a grid."),
+            Self::Object => write!(f, "The sheet has no grid and instead has an object like a chart or image."),
+            Self::DataSource => write!(f, "The sheet connects with an external DataSource and shows the preview of data."),
+            Self::Unknown => write!(f, "A value unknown to this version of the library."),
+            _ => write!(f, "Default value, do not use."),
+        }
+    }
+}
+//endregion
+
+//region RecalculationInterval
+/// How often volatile functions such as `NOW` are recalculated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RecalculationInterval {
+    RecalculationIntervalUnspecified,
+    OnChange,
+    Minute,
+    Hour,
+    /// A value unknown to this version of the library, e.g. one added to the API since.
+    /// It's only ever read: sending it back fails rather than sending a value the API rejects.
+    #[serde(other, skip_serializing)]
+    Unknown,
+}
+
+impl TryFrom<RecalculationInterval> for &str {
+    type Error = Error;
+
+    /// Fails for `RecalculationInterval::Unknown`, whose value isn't known.
+    fn try_from(value: RecalculationInterval) -> Result<Self> {
+        Ok(match value {
+            RecalculationInterval::RecalculationIntervalUnspecified => "RECALCULATION_INTERVAL_UNSPECIFIED",
+            RecalculationInterval::OnChange => "ON_CHANGE",
+            RecalculationInterval::Minute => "MINUTE",
+            RecalculationInterval::Hour => "HOUR",
+            RecalculationInterval::Unknown => return Err(unknown("RecalculationInterval")),
+        })
+    }
+}
+
+impl From<&str> for RecalculationInterval {
+    fn from(text: &str) -> Self {
+        match text {
+            "ON_CHANGE" => Self::OnChange,
+            "MINUTE" => Self::Minute,
+            "HOUR" => Self::Hour,
+            "RECALCULATION_INTERVAL_UNSPECIFIED" => Self::RecalculationIntervalUnspecified,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl Display for RecalculationInterval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OnChange => write!(f, "Volatile functions are updated on every change."),
+            Self::Minute => write!(f, "Volatile functions are updated on every change and every minute."),
+            Self::Hour => write!(f, "Volatile functions are updated on every change and hourly."),
+            Self::Unknown => write!(f, "A value unknown to this version of the library."),
+            _ => write!(f, "Default value. This value must not be used."),
+        }
+    }
+}
+//endregion
+
+//region ValueInputOption
+/// How written values are interpreted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ValueInputOption {
+    InputValueOptionUnspecified,
+    Raw,
+    UserEntered,
+}
+
+impl From<ValueInputOption> for &str {
+    fn from(value: ValueInputOption) -> Self {
+        match value {
+            ValueInputOption::InputValueOptionUnspecified => "INPUT_VALUE_OPTION_UNSPECIFIED",
+            ValueInputOption::Raw => "RAW",
+            ValueInputOption::UserEntered => "USER_ENTERED",
+        }
+    }
+}
+
+impl From<&str> for ValueInputOption {
+    fn from(text: &str) -> Self {
+        match text {
+            "RAW" => Self::Raw,
+            "USER_ENTERED" => Self::UserEntered,
+            _ => Self::InputValueOptionUnspecified,
+        }
+    }
+}
+
+impl Display for ValueInputOption {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Raw => write!(f, "The values the user has entered will not be parsed and will be stored as-is."),
+            Self::UserEntered => write!(f, "The values will be parsed as if the user typed them into the UI."),
+            _ => write!(f, "Default input value. This value must not be used."),
+        }
+    }
+}
+//endregion
+
+//region ValueRenderOption
+/// How read values are rendered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ValueRenderOption {
+    FormattedValue,
+    UnformattedValue,
+    Formula,
+}
+
+impl From<ValueRenderOption> for &str {
+    fn from(value: ValueRenderOption) -> Self {
+        match value {
+            ValueRenderOption::FormattedValue => "FORMATTED_VALUE",
+            ValueRenderOption::UnformattedValue => "UNFORMATTED_VALUE",
+            ValueRenderOption::Formula => "FORMULA",
+        }
+    }
+}
+
+impl From<&str> for ValueRenderOption {
+    fn from(text: &str) -> Self {
+        match text {
+            "UNFORMATTED_VALUE" => Self::UnformattedValue,
+            "FORMULA" => Self::Formula,
+            _ => Self::FormattedValue,
+        }
+    }
+}
+
+impl Display for ValueRenderOption {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FormattedValue => write!(f, "Values will be calculated & formatted in the response according to the cell's formatting."),
+            Self::UnformattedValue => write!(f, "Values will be calculated, but not formatted in the reply."),
+            Self::Formula => write!(f, "Values will not be calculated. The reply will include the formulas."),
+        }
+    }
+}
+//endregion
+
+//region DateTimeRenderOption
+/// How dates, times, and durations are rendered when values are not rendered as `FORMATTED_VALUE`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DateTimeRenderOption {
+    SerialNumber,
+    FormattedString,
+}
+
+impl From<DateTimeRenderOption> for &str {
+    fn from(value: DateTimeRenderOption) -> Self {
+        match value {
+            DateTimeRenderOption::SerialNumber => "SERIAL_NUMBER",
+            DateTimeRenderOption::FormattedString => "FORMATTED_STRING",
+        }
+    }
+}
+
+impl From<&str> for DateTimeRenderOption {
+    fn from(text: &str) -> Self {
+        match text {
+            "FORMATTED_STRING" => Self::FormattedString,
+            _ => Self::SerialNumber,
+        }
+    }
+}
+
+impl Display for DateTimeRenderOption {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SerialNumber => write!(f, "Dates, times and durations are output as doubles in \"serial number\" format."),
+            Self::FormattedString => write!(f, "Dates, times and durations are output as strings in their given number format."),
+        }
+    }
+}
+//endregion
+
+//region InsertDataOption
+/// How existing data is changed when new data is appended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum InsertDataOption {
+    Overwrite,
+    InsertRows,
+}
+
+impl From<InsertDataOption> for &str {
+    fn from(value: InsertDataOption) -> Self {
+        match value {
+            InsertDataOption::Overwrite => "OVERWRITE",
+            InsertDataOption::InsertRows => "INSERT_ROWS",
+        }
+    }
+}
+
+impl From<&str> for InsertDataOption {
+    fn from(text: &str) -> Self {
+        match text {
+            "INSERT_ROWS" => Self::InsertRows,
+            _ => Self::Overwrite,
+        }
+    }
+}
+
+impl Display for InsertDataOption {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Overwrite => write!(f, "The new data overwrites existing data in the areas it is written."),
+            Self::InsertRows => write!(f, "Rows are inserted for the new data."),
+        }
+    }
+}
+//endregion
+
+//region LocationMatchingStrategy
+/// How developer metadata lookups match the location of metadata.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LocationMatchingStrategy {
+    DeveloperMetadataLocationMatchingStrategyUnspecified,
+    ExactLocation,
+    IntersectingLocation,
+}
+
+impl From<LocationMatchingStrategy> for &str {
+    fn from(value: LocationMatchingStrategy) -> Self {
+        match value {
+            LocationMatchingStrategy::DeveloperMetadataLocationMatchingStrategyUnspecified => {
+                "DEVELOPER_METADATA_LOCATION_MATCHING_STRATEGY_UNSPECIFIED"
+            }
+            LocationMatchingStrategy::ExactLocation => "EXACT_LOCATION",
+            LocationMatchingStrategy::IntersectingLocation => "INTERSECTING_LOCATION",
         }
     }
 }
-//endregion
\ No newline at end of file
+
+impl From<&str> for LocationMatchingStrategy {
+    fn from(text: &str) -> Self {
+        match text {
+            "EXACT_LOCATION" => Self::ExactLocation,
+            "INTERSECTING_LOCATION" => Self::IntersectingLocation,
+            _ => Self::DeveloperMetadataLocationMatchingStrategyUnspecified,
+        }
+    }
+}
+
+impl Display for LocationMatchingStrategy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ExactLocation => write!(f, "Only developer metadata associated on the exact location specified is matched."),
+            Self::IntersectingLocation => write!(f, "Developer metadata associated on intersecting locations is matched too."),
+            _ => write!(f, "Default value. This value must not be used."),
+        }
+    }
+}
+//endregion
+
+//region ErrorType
+/// The type of error of a cell.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErrorType {
+    ErrorTypeUnspecified,
+    Error,
+    NullValue,
+    DivideByZero,
+    Value,
+    Ref,
+    Name,
+    Num,
+    #[serde(rename = "N_A")]
+    NA,
+    Loading,
+    /// A value unknown to this version of the library, e.g. one added to the API since.
+    /// It's only ever read: sending it back fails rather than sending a value the API rejects.
+    #[serde(other, skip_serializing)]
+    Unknown,
+}
+
+impl TryFrom<ErrorType> for &str {
+    type Error = Error;
+
+    /// Fails for `ErrorType::Unknown`, whose value isn't known.
+    fn try_from(value: ErrorType) -> Result<Self> {
+        Ok(match value {
+            ErrorType::ErrorTypeUnspecified => "ERROR_TYPE_UNSPECIFIED",
+            ErrorType::Error => "ERROR",
+            ErrorType::NullValue => "NULL_VALUE",
+            ErrorType::DivideByZero => "DIVIDE_BY_ZERO",
+            ErrorType::Value => "VALUE",
+            ErrorType::Ref => "REF",
+            ErrorType::Name => "NAME",
+            ErrorType::Num => "NUM",
+            ErrorType::NA => "N_A",
+            ErrorType::Loading => "LOADING",
+            ErrorType::Unknown => return Err(unknown("ErrorType")),
+        })
+    }
+}
+
+impl From<&str> for ErrorType {
+    fn from(text: &str) -> Self {
+        match text {
+            "ERROR" => Self::Error,
+            "NULL_VALUE" => Self::NullValue,
+            "DIVIDE_BY_ZERO" => Self::DivideByZero,
+            "VALUE" => Self::Value,
+            "REF" => Self::Ref,
+            "NAME" => Self::Name,
+            "NUM" => Self::Num,
+            "N_A" => Self::NA,
+            "LOADING" => Self::Loading,
+            "ERROR_TYPE_UNSPECIFIED" => Self::ErrorTypeUnspecified,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl Display for ErrorType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Error => write!(f, "Corresponds to the `#ERROR!` error."),
+            Self::NullValue => write!(f, "Corresponds to the `#NULL!` error."),
+            Self::DivideByZero => write!(f, "Corresponds to the `#DIV/0` error."),
+            Self::Value => write!(f, "Corresponds to the `#VALUE!` error."),
+            Self::Ref => write!(f, "Corresponds to the `#REF!` error."),
+            Self::Name => write!(f, "Corresponds to the `#NAME?` error."),
+            Self::Num => write!(f, "Corresponds to the `#NUM!` error."),
+            Self::NA => write!(f, "Corresponds to the `#N/A` error."),
+            Self::Loading => write!(f, "Corresponds to the `Loading...` state."),
+            Self::Unknown => write!(f, "A value unknown to this version of the library."),
+            _ => write!(f, "The default error type, do not use this."),
+        }
+    }
+}
+//endregion
+
+//region RelativeDate
+/// A date relative to the current date, in date conditions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RelativeDate {
+    RelativeDateUnspecified,
+    PastYear,
+    PastMonth,
+    PastWeek,
+    Yesterday,
+    Today,
+    Tomorrow,
+    /// A value unknown to this version of the library, e.g. one added to the API since.
+    /// It's only ever read: sending it back fails rather than sending a value the API rejects.
+    #[serde(other, skip_serializing)]
+    Unknown,
+}
+
+impl TryFrom<RelativeDate> for &str {
+    type Error = Error;
+
+    /// Fails for `RelativeDate::Unknown`, whose value isn't known.
+    fn try_from(value: RelativeDate) -> Result<Self> {
+        Ok(match value {
+            RelativeDate::RelativeDateUnspecified => "RELATIVE_DATE_UNSPECIFIED",
+            RelativeDate::PastYear => "PAST_YEAR",
+            RelativeDate::PastMonth => "PAST_MONTH",
+            RelativeDate::PastWeek => "PAST_WEEK",
+            RelativeDate::Yesterday => "YESTERDAY",
+            RelativeDate::Today => "TODAY",
+            RelativeDate::Tomorrow => "TOMORROW",
+            RelativeDate::Unknown => return Err(unknown("RelativeDate")),
+        })
+    }
+}
+
+impl From<&str> for RelativeDate {
+    fn from(text: &str) -> Self {
+        match text {
+            "PAST_YEAR" => Self::PastYear,
+            "PAST_MONTH" => Self::PastMonth,
+            "PAST_WEEK" => Self::PastWeek,
+            "YESTERDAY" => Self::Yesterday,
+            "TODAY" => Self::Today,
+            "TOMORROW" => Self::Tomorrow,
+            "RELATIVE_DATE_UNSPECIFIED" => Self::RelativeDateUnspecified,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl Display for RelativeDate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::PastYear => write!(f, "The value is one year before today."),
+            Self::PastMonth => write!(f, "The value is one month before today."),
+            Self::PastWeek => write!(f, "The value is one week before today."),
+            Self::Yesterday => write!(f, "The value is yesterday."),
+            Self::Today => write!(f, "The value is today."),
+            Self::Tomorrow => write!(f, "The value is tomorrow."),
+            Self::Unknown => write!(f, "A value unknown to this version of the library."),
+            _ => write!(f, "Default value, do not use."),
+        }
+    }
+}
+//endregion
+
+//region ConditionType
+/// The type of condition of a data validation, conditional format or filter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ConditionType {
+    ConditionTypeUnspecified,
+    NumberGreater,
+    NumberGreaterThanEq,
+    NumberLess,
+    NumberLessThanEq,
+    NumberEq,
+    NumberNotEq,
+    NumberBetween,
+    NumberNotBetween,
+    TextContains,
+    TextNotContains,
+    TextStartsWith,
+    TextEndsWith,
+    TextEq,
+    TextIsEmail,
+    TextIsUrl,
+    DateEq,
+    DateBefore,
+    DateAfter,
+    DateOnOrBefore,
+    DateOnOrAfter,
+    DateBetween,
+    DateNotBetween,
+    DateIsValid,
+    OneOfRange,
+    OneOfList,
+    Blank,
+    NotBlank,
+    CustomFormula,
+    Boolean,
+    TextNotEq,
+    DateNotEq,
+    FilterExpression,
+    /// A value unknown to this version of the library, e.g. one added to the API since.
+    /// It's only ever read: sending it back fails rather than sending a value the API rejects.
+    #[serde(other, skip_serializing)]
+    Unknown,
+}
+
+impl TryFrom<ConditionType> for &str {
+    type Error = Error;
+
+    /// Fails for `ConditionType::Unknown`, whose value isn't known.
+    fn try_from(value: ConditionType) -> Result<Self> {
+        Ok(match value {
+            ConditionType::ConditionTypeUnspecified => "CONDITION_TYPE_UNSPECIFIED",
+            ConditionType::NumberGreater => "NUMBER_GREATER",
+            ConditionType::NumberGreaterThanEq => "NUMBER_GREATER_THAN_EQ",
+            ConditionType::NumberLess => "NUMBER_LESS",
+            ConditionType::NumberLessThanEq => "NUMBER_LESS_THAN_EQ",
+            ConditionType::NumberEq => "NUMBER_EQ",
+            ConditionType::NumberNotEq => "NUMBER_NOT_EQ",
+            ConditionType::NumberBetween => "NUMBER_BETWEEN",
+            ConditionType::NumberNotBetween => "NUMBER_NOT_BETWEEN",
+            ConditionType::TextContains => "TEXT_CONTAINS",
+            ConditionType::TextNotContains => "TEXT_NOT_CONTAINS",
+            ConditionType::TextStartsWith => "TEXT_STARTS_WITH",
+            ConditionType::TextEndsWith => "TEXT_ENDS_WITH",
+            ConditionType::TextEq => "TEXT_EQ",
+            ConditionType::TextIsEmail => "TEXT_IS_EMAIL",
+            ConditionType::TextIsUrl => "TEXT_IS_URL",
+            ConditionType::DateEq => "DATE_EQ",
+            ConditionType::DateBefore => "DATE_BEFORE",
+            ConditionType::DateAfter => "DATE_AFTER",
+            ConditionType::DateOnOrBefore => "DATE_ON_OR_BEFORE",
+            ConditionType::DateOnOrAfter => "DATE_ON_OR_AFTER",
+            ConditionType::DateBetween => "DATE_BETWEEN",
+            ConditionType::DateNotBetween => "DATE_NOT_BETWEEN",
+            ConditionType::DateIsValid => "DATE_IS_VALID",
+            ConditionType::OneOfRange => "ONE_OF_RANGE",
+            ConditionType::OneOfList => "ONE_OF_LIST",
+            ConditionType::Blank => "BLANK",
+            ConditionType::NotBlank => "NOT_BLANK",
+            ConditionType::CustomFormula => "CUSTOM_FORMULA",
+            ConditionType::Boolean => "BOOLEAN",
+            ConditionType::TextNotEq => "TEXT_NOT_EQ",
+            ConditionType::DateNotEq => "DATE_NOT_EQ",
+            ConditionType::FilterExpression => "FILTER_EXPRESSION",
+            ConditionType::Unknown => return Err(unknown("ConditionType")),
+        })
+    }
+}
+
+impl From<&str> for ConditionType {
+    fn from(text: &str) -> Self {
+        match text {
+            "NUMBER_GREATER" => Self::NumberGreater,
+            "NUMBER_GREATER_THAN_EQ" => Self::NumberGreaterThanEq,
+            "NUMBER_LESS" => Self::NumberLess,
+            "NUMBER_LESS_THAN_EQ" => Self::NumberLessThanEq,
+            "NUMBER_EQ" => Self::NumberEq,
+            "NUMBER_NOT_EQ" => Self::NumberNotEq,
+            "NUMBER_BETWEEN" => Self::NumberBetween,
+            "NUMBER_NOT_BETWEEN" => Self::NumberNotBetween,
+            "TEXT_CONTAINS" => Self::TextContains,
+            "TEXT_NOT_CONTAINS" => Self::TextNotContains,
+            "TEXT_STARTS_WITH" => Self::TextStartsWith,
+            "TEXT_ENDS_WITH" => Self::TextEndsWith,
+            "TEXT_EQ" => Self::TextEq,
+            "TEXT_IS_EMAIL" => Self::TextIsEmail,
+            "TEXT_IS_URL" => Self::TextIsUrl,
+            "DATE_EQ" => Self::DateEq,
+            "DATE_BEFORE" => Self::DateBefore,
+            "DATE_AFTER" => Self::DateAfter,
+            "DATE_ON_OR_BEFORE" => Self::DateOnOrBefore,
+            "DATE_ON_OR_AFTER" => Self::DateOnOrAfter,
+            "DATE_BETWEEN" => Self::DateBetween,
+            "DATE_NOT_BETWEEN" => Self::DateNotBetween,
+            "DATE_IS_VALID" => Self::DateIsValid,
+            "ONE_OF_RANGE" => Self::OneOfRange,
+            "ONE_OF_LIST" => Self::OneOfList,
+            "BLANK" => Self::Blank,
+            "NOT_BLANK" => Self::NotBlank,
+            "CUSTOM_FORMULA" => Self::CustomFormula,
+            "BOOLEAN" => Self::Boolean,
+            "TEXT_NOT_EQ" => Self::TextNotEq,
+            "DATE_NOT_EQ" => Self::DateNotEq,
+            "FILTER_EXPRESSION" => Self::FilterExpression,
+            "CONDITION_TYPE_UNSPECIFIED" => Self::ConditionTypeUnspecified,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl Display for ConditionType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NumberGreater => write!(f, "The cell's value must be greater than the condition's value."),
+            Self::NumberGreaterThanEq => write!(f, "The cell's value must be greater than or equal to the condition's value."),
+            Self::NumberLess => write!(f, "The cell's value must be less than the condition's value."),
+            Self::NumberLessThanEq => write!(f, "The cell's value must be less than or equal to the condition's value."),
+            Self::NumberEq => write!(f, "The cell's value must be equal to the condition's value."),
+            Self::NumberNotEq => write!(f, "The cell's value must be not equal to the condition's value."),
+            Self::NumberBetween => write!(f, "The cell's value must be between the two condition values."),
+            Self::NumberNotBetween => write!(f, "The cell's value must not be between the two condition values."),
+            Self::TextContains => write!(f, "The cell's value must contain the condition's value."),
+            Self::TextNotContains => write!(f, "The cell's value must not contain the condition's value."),
+            Self::TextStartsWith => write!(f, "The cell's value must start with the condition's value."),
+            Self::TextEndsWith => write!(f, "The cell's value must end with the condition's value."),
+            Self::TextEq => write!(f, "The cell's value must be exactly the condition's value."),
+            Self::TextIsEmail => write!(f, "The cell's value must be a valid email address."),
+            Self::TextIsUrl => write!(f, "The cell's value must be a valid URL."),
+            Self::DateEq => write!(f, "The cell's value must be the same date as the condition's value."),
+            Self::DateBefore => write!(f, "The cell's value must be before the date of the condition's value."),
+            Self::DateAfter => write!(f, "The cell's value must be after the date of the condition's value."),
+            Self::DateOnOrBefore => write!(f, "The cell's value must be on or before the date of the condition's value."),
+            Self::DateOnOrAfter => write!(f, "The cell's value must be on or after the date of the condition's value."),
+            Self::DateBetween => write!(f, "The cell's value must be between the dates of the two condition values."),
+            Self::DateNotBetween => write!(f, "The cell's value must be outside the dates of the two condition values."),
+            Self::DateIsValid => write!(f, "The cell's value must be a date."),
+            Self::OneOfRange => write!(f, "The cell's value must be listed in the grid in the condition value's range."),
+            Self::OneOfList => write!(f, "The cell's value must be in the list of condition values."),
+            Self::Blank => write!(f, "The cell's value must be empty."),
+            Self::NotBlank => write!(f, "The cell's value must not be empty."),
+            Self::CustomFormula => write!(f, "The condition's formula must evaluate to true."),
+            Self::Boolean => write!(f, "The cell's value must be TRUE/FALSE or in the list of condition values."),
+            Self::TextNotEq => write!(f, "The cell's value must be exactly not the condition's value."),
+            Self::DateNotEq => write!(f, "The cell's value must be exactly not the condition's value."),
+            Self::FilterExpression => write!(f, "The cell's value must follow the pattern specified."),
+            Self::Unknown => write!(f, "A value unknown to this version of the library."),
+            _ => write!(f, "The default value, do not use."),
+        }
+    }
+}
+//endregion
+
+//region NumberFormatType
+/// The kind of number format of a cell.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum NumberFormatType {
+    NumberFormatTypeUnspecified,
+    Text,
+    Number,
+    Percent,
+    Currency,
+    Date,
+    Time,
+    DateTime,
+    Scientific,
+    /// A value unknown to this version of the library, e.g. one added to the API since.
+    /// It's only ever read: sending it back fails rather than sending a value the API rejects.
+    #[serde(other, skip_serializing)]
+    Unknown,
+}
+
+impl TryFrom<NumberFormatType> for &str {
+    type Error = Error;
+
+    /// Fails for `NumberFormatType::Unknown`, whose value isn't known.
+    fn try_from(value: NumberFormatType) -> Result<Self> {
+        Ok(match value {
+            NumberFormatType::NumberFormatTypeUnspecified => "NUMBER_FORMAT_TYPE_UNSPECIFIED",
+            NumberFormatType::Text => "TEXT",
+            NumberFormatType::Number => "NUMBER",
+            NumberFormatType::Percent => "PERCENT",
+            NumberFormatType::Currency => "CURRENCY",
+            NumberFormatType::Date => "DATE",
+            NumberFormatType::Time => "TIME",
+            NumberFormatType::DateTime => "DATE_TIME",
+            NumberFormatType::Scientific => "SCIENTIFIC",
+            NumberFormatType::Unknown => return Err(unknown("NumberFormatType")),
+        })
+    }
+}
+
+impl From<&str> for NumberFormatType {
+    fn from(text: &str) -> Self {
+        match text {
+            "TEXT" => Self::Text,
+            "NUMBER" => Self::Number,
+            "PERCENT" => Self::Percent,
+            "CURRENCY" => Self::Currency,
+            "DATE" => Self::Date,
+            "TIME" => Self::Time,
+            "DATE_TIME" => Self::DateTime,
+            "SCIENTIFIC" => Self::Scientific,
+            "NUMBER_FORMAT_TYPE_UNSPECIFIED" => Self::NumberFormatTypeUnspecified,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl Display for NumberFormatType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Text => write!(f, "Text formatting, e.g `1000.12`."),
+            Self::Number => write!(f, "Number formatting, e.g, `1,000.12`."),
+            Self::Percent => write!(f, "Percent formatting, e.g `10.12%`."),
+            Self::Currency => write!(f, "Currency formatting, e.g `$1,000.12`."),
+            Self::Date => write!(f, "Date formatting, e.g `9/26/2008`."),
+            Self::Time => write!(f, "Time formatting, e.g `3:59:00 PM`."),
+            Self::DateTime => write!(f, "Date+Time formatting, e.g `9/26/08 15:59:00`."),
+            Self::Scientific => write!(f, "Scientific number formatting, e.g `1.01E+03`."),
+            Self::Unknown => write!(f, "A value unknown to this version of the library."),
+            _ => write!(f, "The number format is not specified and is based on the contents of the cell. Do not explicitly use this."),
+        }
+    }
+}
+//endregion
+
+//region VerticalAlignment
+/// The vertical alignment of text in a cell.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum VerticalAlignment {
+    VerticalAlignUnspecified,
+    Top,
+    Middle,
+    Bottom,
+    /// A value unknown to this version of the library, e.g. one added to the API since.
+    /// It's only ever read: sending it back fails rather than sending a value the API rejects.
+    #[serde(other, skip_serializing)]
+    Unknown,
+}
+
+impl TryFrom<VerticalAlignment> for &str {
+    type Error = Error;
+
+    /// Fails for `VerticalAlignment::Unknown`, whose value isn't known.
+    fn try_from(value: VerticalAlignment) -> Result<Self> {
+        Ok(match value {
+            VerticalAlignment::VerticalAlignUnspecified => "VERTICAL_ALIGN_UNSPECIFIED",
+            VerticalAlignment::Top => "TOP",
+            VerticalAlignment::Middle => "MIDDLE",
+            VerticalAlignment::Bottom => "BOTTOM",
+            VerticalAlignment::Unknown => return Err(unknown("VerticalAlignment")),
+        })
+    }
+}
+
+impl From<&str> for VerticalAlignment {
+    fn from(text: &str) -> Self {
+        match text {
+            "TOP" => Self::Top,
+            "MIDDLE" => Self::Middle,
+            "BOTTOM" => Self::Bottom,
+            "VERTICAL_ALIGN_UNSPECIFIED" => Self::VerticalAlignUnspecified,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl Display for VerticalAlignment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Top => write!(f, "The text is explicitly aligned to the top of the cell."),
+            Self::Middle => write!(f, "The text is explicitly aligned to the middle of the cell."),
+            Self::Bottom => write!(f, "The text is explicitly aligned to the bottom of the cell."),
+            Self::Unknown => write!(f, "A value unknown to this version of the library."),
+            _ => write!(f, "The vertical alignment is not specified. Do not use this."),
+        }
+    }
+}
+//endregion
+
+//region WrapStrategy
+/// How text that doesn't fit in a cell is wrapped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WrapStrategy {
+    WrapStrategyUnspecified,
+    OverflowCell,
+    LegacyWrap,
+    Clip,
+    Wrap,
+    /// A value unknown to this version of the library, e.g. one added to the API since.
+    /// It's only ever read: sending it back fails rather than sending a value the API rejects.
+    #[serde(other, skip_serializing)]
+    Unknown,
+}
+
+impl TryFrom<WrapStrategy> for &str {
+    type Error = Error;
+
+    /// Fails for `WrapStrategy::Unknown`, whose value isn't known.
+    fn try_from(value: WrapStrategy) -> Result<Self> {
+        Ok(match value {
+            WrapStrategy::WrapStrategyUnspecified => "WRAP_STRATEGY_UNSPECIFIED",
+            WrapStrategy::OverflowCell => "OVERFLOW_CELL",
+            WrapStrategy::LegacyWrap => "LEGACY_WRAP",
+            WrapStrategy::Clip => "CLIP",
+            WrapStrategy::Wrap => "WRAP",
+            WrapStrategy::Unknown => return Err(unknown("WrapStrategy")),
+        })
+    }
+}
+
+impl From<&str> for WrapStrategy {
+    fn from(text: &str) -> Self {
+        match text {
+            "OVERFLOW_CELL" => Self::OverflowCell,
+            "LEGACY_WRAP" => Self::LegacyWrap,
+            "CLIP" => Self::Clip,
+            "WRAP" => Self::Wrap,
+            "WRAP_STRATEGY_UNSPECIFIED" => Self::WrapStrategyUnspecified,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl Display for WrapStrategy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OverflowCell => write!(f, "Lines longer than the cell width are written in the next cell over, so long as that cell is empty."),
+            Self::LegacyWrap => write!(f, "This wrap strategy represents the old Google Sheets wrap strategy where words longer than a line are clipped."),
+            Self::Clip => write!(f, "Lines longer than the cell width are clipped."),
+            Self::Wrap => write!(f, "Words longer than a line are wrapped at the character level rather than clipped."),
+            Self::Unknown => write!(f, "A value unknown to this version of the library."),
+            _ => write!(f, "The default value, do not use."),
+        }
+    }
+}
+//endregion
+
+//region TextDirection
+/// The direction of the text in a cell.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TextDirection {
+    TextDirectionUnspecified,
+    LeftToRight,
+    RightToLeft,
+    /// A value unknown to this version of the library, e.g. one added to the API since.
+    /// It's only ever read: sending it back fails rather than sending a value the API rejects.
+    #[serde(other, skip_serializing)]
+    Unknown,
+}
+
+impl TryFrom<TextDirection> for &str {
+    type Error = Error;
+
+    /// Fails for `TextDirection::Unknown`, whose value isn't known.
+    fn try_from(value: TextDirection) -> Result<Self> {
+        Ok(match value {
+            TextDirection::TextDirectionUnspecified => "TEXT_DIRECTION_UNSPECIFIED",
+            TextDirection::LeftToRight => "LEFT_TO_RIGHT",
+            TextDirection::RightToLeft => "RIGHT_TO_LEFT",
+            TextDirection::Unknown => return Err(unknown("TextDirection")),
+        })
+    }
+}
+
+impl From<&str> for TextDirection {
+    fn from(text: &str) -> Self {
+        match text {
+            "LEFT_TO_RIGHT" => Self::LeftToRight,
+            "RIGHT_TO_LEFT" => Self::RightToLeft,
+            "TEXT_DIRECTION_UNSPECIFIED" => Self::TextDirectionUnspecified,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl Display for TextDirection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LeftToRight => write!(f, "The text direction of left-to-right was set by the user."),
+            Self::RightToLeft => write!(f, "The text direction of right-to-left was set by the user."),
+            Self::Unknown => write!(f, "A value unknown to this version of the library."),
+            _ => write!(f, "The text direction is not specified. Do not use this."),
+        }
+    }
+}
+//endregion
+
+//region HyperlinkDisplayType
+/// Whether to explicitly render a hyperlink.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum HyperlinkDisplayType {
+    HyperlinkDisplayTypeUnspecified,
+    Linked,
+    PlainText,
+    /// A value unknown to this version of the library, e.g. one added to the API since.
+    /// It's only ever read: sending it back fails rather than sending a value the API rejects.
+    #[serde(other, skip_serializing)]
+    Unknown,
+}
+
+impl TryFrom<HyperlinkDisplayType> for &str {
+    type Error = Error;
+
+    /// Fails for `HyperlinkDisplayType::Unknown`, whose value isn't known.
+    fn try_from(value: HyperlinkDisplayType) -> Result<Self> {
+        Ok(match value {
+            HyperlinkDisplayType::HyperlinkDisplayTypeUnspecified => "HYPERLINK_DISPLAY_TYPE_UNSPECIFIED",
+            HyperlinkDisplayType::Linked => "LINKED",
+            HyperlinkDisplayType::PlainText => "PLAIN_TEXT",
+            HyperlinkDisplayType::Unknown => return Err(unknown("HyperlinkDisplayType")),
+        })
+    }
+}
+
+impl From<&str> for HyperlinkDisplayType {
+    fn from(text: &str) -> Self {
+        match text {
+            "LINKED" => Self::Linked,
+            "PLAIN_TEXT" => Self::PlainText,
+            "HYPERLINK_DISPLAY_TYPE_UNSPECIFIED" => Self::HyperlinkDisplayTypeUnspecified,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl Display for HyperlinkDisplayType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Linked => write!(f, "A hyperlink should be explicitly rendered."),
+            Self::PlainText => write!(f, "A hyperlink should not be rendered."),
+            Self::Unknown => write!(f, "A value unknown to this version of the library."),
+            _ => write!(f, "The default value: the hyperlink is rendered. Do not use this."),
+        }
+    }
+}
+//endregion
+
+//region BorderStyle
+/// The style of a border.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BorderStyle {
+    StyleUnspecified,
+    Dotted,
+    Dashed,
+    Solid,
+    SolidMedium,
+    SolidThick,
+    None,
+    Double,
+    /// A value unknown to this version of the library, e.g. one added to the API since.
+    /// It's only ever read: sending it back fails rather than sending a value the API rejects.
+    #[serde(other, skip_serializing)]
+    Unknown,
+}
+
+impl TryFrom<BorderStyle> for &str {
+    type Error = Error;
+
+    /// Fails for `BorderStyle::Unknown`, whose value isn't known.
+    fn try_from(value: BorderStyle) -> Result<Self> {
+        Ok(match value {
+            BorderStyle::StyleUnspecified => "STYLE_UNSPECIFIED",
+            BorderStyle::Dotted => "DOTTED",
+            BorderStyle::Dashed => "DASHED",
+            BorderStyle::Solid => "SOLID",
+            BorderStyle::SolidMedium => "SOLID_MEDIUM",
+            BorderStyle::SolidThick => "SOLID_THICK",
+            BorderStyle::None => "NONE",
+            BorderStyle::Double => "DOUBLE",
+            BorderStyle::Unknown => return Err(unknown("BorderStyle")),
+        })
+    }
+}
+
+impl From<&str> for BorderStyle {
+    fn from(text: &str) -> Self {
+        match text {
+            "DOTTED" => Self::Dotted,
+            "DASHED" => Self::Dashed,
+            "SOLID" => Self::Solid,
+            "SOLID_MEDIUM" => Self::SolidMedium,
+            "SOLID_THICK" => Self::SolidThick,
+            "NONE" => Self::None,
+            "DOUBLE" => Self::Double,
+            "STYLE_UNSPECIFIED" => Self::StyleUnspecified,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl Display for BorderStyle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Dotted => write!(f, "The border is dotted."),
+            Self::Dashed => write!(f, "The border is dashed."),
+            Self::Solid => write!(f, "The border is a thin solid line."),
+            Self::SolidMedium => write!(f, "The border is a medium solid line."),
+            Self::SolidThick => write!(f, "The border is a thick solid line."),
+            Self::None => write!(f, "No border. Used only when updating a border in order to erase it."),
+            Self::Double => write!(f, "The border is two solid lines."),
+            Self::Unknown => write!(f, "A value unknown to this version of the library."),
+            _ => write!(f, "The style is not specified. Do not use this."),
+        }
+    }
+}
+//endregion
+
+//region DataExecutionState
+/// The state of a data execution, e.g. the refresh of a data source object.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DataExecutionState {
+    DataExecutionStateUnspecified,
+    NotStarted,
+    Running,
+    Cancelling,
+    Succeeded,
+    Failed,
+    /// A value unknown to this version of the library, e.g. one added to the API since.
+    /// It's only ever read: sending it back fails rather than sending a value the API rejects.
+    #[serde(other, skip_serializing)]
+    Unknown,
+}
+
+impl TryFrom<DataExecutionState> for &str {
+    type Error = Error;
+
+    /// Fails for `DataExecutionState::Unknown`, whose value isn't known.
+    fn try_from(value: DataExecutionState) -> Result<Self> {
+        Ok(match value {
+            DataExecutionState::DataExecutionStateUnspecified => "DATA_EXECUTION_STATE_UNSPECIFIED",
+            DataExecutionState::NotStarted => "NOT_STARTED",
+            DataExecutionState::Running => "RUNNING",
+            DataExecutionState::Cancelling => "CANCELLING",
+            DataExecutionState::Succeeded => "SUCCEEDED",
+            DataExecutionState::Failed => "FAILED",
+            DataExecutionState::Unknown => return Err(unknown("DataExecutionState")),
+        })
+    }
+}
+
+impl From<&str> for DataExecutionState {
+    fn from(text: &str) -> Self {
+        match text {
+            "NOT_STARTED" => Self::NotStarted,
+            "RUNNING" => Self::Running,
+            "CANCELLING" => Self::Cancelling,
+            "SUCCEEDED" => Self::Succeeded,
+            "FAILED" => Self::Failed,
+            "DATA_EXECUTION_STATE_UNSPECIFIED" => Self::DataExecutionStateUnspecified,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl Display for DataExecutionState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotStarted => write!(f, "The data execution has not started."),
+            Self::Running => write!(f, "The data execution has started and is running."),
+            Self::Cancelling => write!(f, "The data execution is currently being cancelled."),
+            Self::Succeeded => write!(f, "The data execution has completed successfully."),
+            Self::Failed => write!(f, "The data execution has completed with errors."),
+            Self::Unknown => write!(f, "A value unknown to this version of the library."),
+            _ => write!(f, "The default value, do not use."),
+        }
+    }
+}
+//endregion
+
+//region DataExecutionErrorCode
+/// Why a data execution failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DataExecutionErrorCode {
+    DataExecutionErrorCodeUnspecified,
+    TimedOut,
+    TooManyRows,
+    TooManyColumns,
+    TooManyCells,
+    Engine,
+    ParameterInvalid,
+    UnsupportedDataType,
+    DuplicateColumnNames,
+    Interrupted,
+    ConcurrentQuery,
+    Other,
+    TooManyCharsPerCell,
+    DataNotFound,
+    PermissionDenied,
+    MissingColumnAlias,
+    ObjectNotFound,
+    ObjectInErroredState,
+    ObjectSpecInvalid,
+    DataExecutionCancelled,
+    /// A value unknown to this version of the library, e.g. one added to the API since.
+    /// It's only ever read: sending it back fails rather than sending a value the API rejects.
+    #[serde(other, skip_serializing)]
+    Unknown,
+}
+
+impl TryFrom<DataExecutionErrorCode> for &str {
+    type Error = Error;
+
+    /// Fails for `DataExecutionErrorCode::Unknown`, whose value isn't known.
+    fn try_from(value: DataExecutionErrorCode) -> Result<Self> {
+        Ok(match value {
+            DataExecutionErrorCode::DataExecutionErrorCodeUnspecified => "DATA_EXECUTION_ERROR_CODE_UNSPECIFIED",
+            DataExecutionErrorCode::TimedOut => "TIMED_OUT",
+            DataExecutionErrorCode::TooManyRows => "TOO_MANY_ROWS",
+            DataExecutionErrorCode::TooManyColumns => "TOO_MANY_COLUMNS",
+            DataExecutionErrorCode::TooManyCells => "TOO_MANY_CELLS",
+            DataExecutionErrorCode::Engine => "ENGINE",
+            DataExecutionErrorCode::ParameterInvalid => "PARAMETER_INVALID",
+            DataExecutionErrorCode::UnsupportedDataType => "UNSUPPORTED_DATA_TYPE",
+            DataExecutionErrorCode::DuplicateColumnNames => "DUPLICATE_COLUMN_NAMES",
+            DataExecutionErrorCode::Interrupted => "INTERRUPTED",
+            DataExecutionErrorCode::ConcurrentQuery => "CONCURRENT_QUERY",
+            DataExecutionErrorCode::Other => "OTHER",
+            DataExecutionErrorCode::TooManyCharsPerCell => "TOO_MANY_CHARS_PER_CELL",
+            DataExecutionErrorCode::DataNotFound => "DATA_NOT_FOUND",
+            DataExecutionErrorCode::PermissionDenied => "PERMISSION_DENIED",
+            DataExecutionErrorCode::MissingColumnAlias => "MISSING_COLUMN_ALIAS",
+            DataExecutionErrorCode::ObjectNotFound => "OBJECT_NOT_FOUND",
+            DataExecutionErrorCode::ObjectInErroredState => "OBJECT_IN_ERRORED_STATE",
+            DataExecutionErrorCode::ObjectSpecInvalid => "OBJECT_SPEC_INVALID",
+            DataExecutionErrorCode::DataExecutionCancelled => "DATA_EXECUTION_CANCELLED",
+            DataExecutionErrorCode::Unknown => return Err(unknown("DataExecutionErrorCode")),
+        })
+    }
+}
+
+impl From<&str> for DataExecutionErrorCode {
+    fn from(text: &str) -> Self {
+        match text {
+            "TIMED_OUT" => Self::TimedOut,
+            "TOO_MANY_ROWS" => Self::TooManyRows,
+            "TOO_MANY_COLUMNS" => Self::TooManyColumns,
+            "TOO_MANY_CELLS" => Self::TooManyCells,
+            "ENGINE" => Self::Engine,
+            "PARAMETER_INVALID" => Self::ParameterInvalid,
+            "UNSUPPORTED_DATA_TYPE" => Self::UnsupportedDataType,
+            "DUPLICATE_COLUMN_NAMES" => Self::DuplicateColumnNames,
+            "INTERRUPTED" => Self::Interrupted,
+            "CONCURRENT_QUERY" => Self::ConcurrentQuery,
+            "OTHER" => Self::Other,
+            "TOO_MANY_CHARS_PER_CELL" => Self::TooManyCharsPerCell,
+            "DATA_NOT_FOUND" => Self::DataNotFound,
+            "PERMISSION_DENIED" => Self::PermissionDenied,
+            "MISSING_COLUMN_ALIAS" => Self::MissingColumnAlias,
+            "OBJECT_NOT_FOUND" => Self::ObjectNotFound,
+            "OBJECT_IN_ERRORED_STATE" => Self::ObjectInErroredState,
+            "OBJECT_SPEC_INVALID" => Self::ObjectSpecInvalid,
+            "DATA_EXECUTION_CANCELLED" => Self::DataExecutionCancelled,
+            "DATA_EXECUTION_ERROR_CODE_UNSPECIFIED" => Self::DataExecutionErrorCodeUnspecified,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl Display for DataExecutionErrorCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TimedOut => write!(f, "The data execution timed out."),
+            Self::TooManyRows => write!(f, "The data execution returns more rows than the limit."),
+            Self::TooManyColumns => write!(f, "The data execution returns more columns than the limit."),
+            Self::TooManyCells => write!(f, "The data execution returns more cells than the limit."),
+            Self::Engine => write!(f, "Error is received from the backend data execution engine, e.g. BigQuery."),
+            Self::ParameterInvalid => write!(f, "One or some of the provided data source parameters are invalid."),
+            Self::UnsupportedDataType => write!(f, "The data execution returns an unsupported data type."),
+            Self::DuplicateColumnNames => write!(f, "The data execution returns duplicate column names or aliases."),
+            Self::Interrupted => write!(f, "The data execution is interrupted. Please refresh later."),
+            Self::ConcurrentQuery => write!(f, "The data execution is currently in progress, can not be refreshed until it completes."),
+            Self::Other => write!(f, "Other errors."),
+            Self::TooManyCharsPerCell => write!(f, "The data execution returns values that exceed the maximum characters allowed in a single cell."),
+            Self::DataNotFound => write!(f, "The database referenced by the data source is not found."),
+            Self::PermissionDenied => write!(f, "The user does not have access to the database referenced by the data source."),
+            Self::MissingColumnAlias => write!(f, "The data execution returns columns with missing aliases."),
+            Self::ObjectNotFound => write!(f, "The data source object does not exist."),
+            Self::ObjectInErroredState => write!(f, "The data source object is currently in error state."),
+            Self::ObjectSpecInvalid => write!(f, "The data source object specification is invalid."),
+            Self::DataExecutionCancelled => write!(f, "The data execution has been cancelled."),
+            Self::Unknown => write!(f, "A value unknown to this version of the library."),
+            _ => write!(f, "The default error code, do not use."),
+        }
+    }
+}
+//endregion
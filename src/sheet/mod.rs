@@ -1,21 +1,214 @@
 pub mod tool;
 pub mod models;
 pub mod enums;
+pub mod requests;
+pub mod values;
 
+#[cfg(test)]
 use serde_json::json;
-use std::fmt::{Display, Formatter};
+#[cfg(test)]
 use super::sheet::models::*;
+use serde::Serialize;
 
-pub struct SheetApi {}
+use self::models::{DataFilter, SheetProperties, Spreadsheet};
+use self::requests::{BatchUpdateSpreadsheetRequest, BatchUpdateSpreadsheetResponse};
+use crate::auth::SPREADSHEETS_SCOPE;
+use crate::call::{encode_segment, with_query, Call};
+use crate::client::{Client, Version};
+use crate::error::Result;
+use crate::transport::{Method, Request};
+use self::values::ValuesApi;
+
+/// The `spreadsheets` resource of the Sheets API.
+pub struct SheetApi {
+    client: Client,
+}
 
 impl SheetApi {
+    /// An API handle sending no credentials, usable with public spreadsheets only.
     pub fn new() -> Self {
-        Self {}
+        Self::with_client(Client::new(Version::V4))
+    }
+
+    /// An API handle sending every request through `client`.
+    pub fn with_client(client: Client) -> Self {
+        Self { client }
+    }
+
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// Creates `spreadsheet` and returns it as created, with its ID and url.
+    pub fn create(&self, spreadsheet: &Spreadsheet) -> Result<Spreadsheet> {
+        self.client.execute(create(spreadsheet))
+    }
+
+    /// Fetches the spreadsheet `spreadsheet_id`, limited to what `request` asks for.
+    pub fn get(&self, spreadsheet_id: &str, request: &GetRequest) -> Result<Spreadsheet> {
+        self.client.execute(get(spreadsheet_id, request))
+    }
+
+    /// Fetches the spreadsheet `spreadsheet_id`, with the data of the ranges matched by the filters of `request`.
+    pub fn get_by_data_filter(&self, spreadsheet_id: &str, request: &GetByDataFilterRequest) -> Result<Spreadsheet> {
+        self.client.execute(get_by_data_filter(spreadsheet_id, request))
+    }
+
+    /// Applies the changes of `request` to the spreadsheet `spreadsheet_id`, all of them or none if one is invalid.
+    pub fn batch_update(
+        &self,
+        spreadsheet_id: &str,
+        request: &BatchUpdateSpreadsheetRequest,
+    ) -> Result<BatchUpdateSpreadsheetResponse> {
+        self.client.execute(batch_update(spreadsheet_id, request))
     }
 
-    // pub fn create() -> Spreadsheet {
-    //     Spreadsheet {}
-    // }
+    /// Copies the sheet `sheet_id` into the spreadsheet `destination_spreadsheet_id`, returning the properties of the copy.
+    pub fn copy_sheet_to(
+        &self,
+        spreadsheet_id: &str,
+        sheet_id: i32,
+        destination_spreadsheet_id: &str,
+    ) -> Result<SheetProperties> {
+        self.client.execute(copy_sheet_to(spreadsheet_id, sheet_id, destination_spreadsheet_id))
+    }
+
+    /// The values of the spreadsheets, without their formatting.
+    pub fn values(&self) -> ValuesApi<'_> {
+        ValuesApi::new(&self.client)
+    }
+}
+
+/// What `SheetApi::get` fetches: the whole spreadsheet without cell data by default.
+#[derive(Clone, Debug, Default)]
+pub struct GetRequest {
+    ranges: Vec<String>,
+    include_grid_data: bool,
+    fields: Option<String>,
+}
+
+impl GetRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only returns the data of `range`, in A1 notation, e.g. `Sheet1!A1:C10`. Can be called several times.
+    pub fn with_range<S: Into<String>>(mut self, range: S) -> Self {
+        self.ranges.push(range.into());
+        self
+    }
+
+    /// Only returns the data of `ranges`, in A1 notation.
+    pub fn with_ranges<I: IntoIterator<Item = S>, S: Into<String>>(mut self, ranges: I) -> Self {
+        self.ranges.extend(ranges.into_iter().map(Into::into));
+        self
+    }
+
+    /// Returns the cells of the sheets in `Sheet::data`. Ignored when `fields` is set.
+    pub fn with_grid_data(mut self, include: bool) -> Self {
+        self.include_grid_data = include;
+        self
+    }
+
+    /// Only returns the fields in `mask`, e.g. `sheets(properties.title,data.rowData.values.formattedValue)`.
+    pub fn with_fields<S: Into<String>>(mut self, mask: S) -> Self {
+        self.fields = Some(mask.into());
+        self
+    }
+
+    fn params(&self) -> Vec<(&str, String)> {
+        let mut params: Vec<_> = self.ranges.iter().map(|range| ("ranges", range.clone())).collect();
+
+        if self.include_grid_data {
+            params.push(("includeGridData", "true".to_string()));
+        }
+        if let Some(fields) = &self.fields {
+            params.push(("fields", fields.clone()));
+        }
+        params
+    }
+}
+
+/// `spreadsheets.create`, shared with the async API.
+pub(crate) fn create(spreadsheet: &Spreadsheet) -> Call<Spreadsheet> {
+    Call::new(Request::new(Method::Post, "spreadsheets"), &[SPREADSHEETS_SCOPE]).with_json(spreadsheet)
+}
+
+/// What `SheetApi::get_by_data_filter` fetches: the whole spreadsheet without cell data by default.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct GetByDataFilterRequest {
+    #[serde(rename(serialize = "dataFilters"))]
+    data_filters: Vec<DataFilter>,
+    #[serde(rename(serialize = "includeGridData"), skip_serializing_if = "std::ops::Not::not")]
+    include_grid_data: bool,
+}
+
+impl GetByDataFilterRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only returns the data of the ranges matched by `filter`. Can be called several times.
+    pub fn with_data_filter(mut self, filter: DataFilter) -> Self {
+        self.data_filters.push(filter);
+        self
+    }
+
+    /// Returns the cells of the sheets in `Sheet::data`.
+    pub fn with_grid_data(mut self, include: bool) -> Self {
+        self.include_grid_data = include;
+        self
+    }
+}
+
+/// `spreadsheets.get`, shared with the async API.
+pub(crate) fn get(spreadsheet_id: &str, request: &GetRequest) -> Call<Spreadsheet> {
+    let path = with_query(format!("spreadsheets/{}", encode_segment(spreadsheet_id)), &request.params());
+
+    Call::new(Request::new(Method::Get, path), &[SPREADSHEETS_SCOPE])
+}
+
+/// `spreadsheets.getByDataFilter`, shared with the async API.
+pub(crate) fn get_by_data_filter(spreadsheet_id: &str, request: &GetByDataFilterRequest) -> Call<Spreadsheet> {
+    let path = format!("spreadsheets/{}:getByDataFilter", encode_segment(spreadsheet_id));
+
+    Call::new(Request::new(Method::Post, path), &[SPREADSHEETS_SCOPE])
+        .with_json(request)
+        .read_only()
+}
+
+/// `spreadsheets.batchUpdate`, shared with the async API.
+pub(crate) fn batch_update(
+    spreadsheet_id: &str,
+    request: &BatchUpdateSpreadsheetRequest,
+) -> Call<BatchUpdateSpreadsheetResponse> {
+    let path = format!("spreadsheets/{}:batchUpdate", encode_segment(spreadsheet_id));
+
+    Call::new(Request::new(Method::Post, path), &[SPREADSHEETS_SCOPE]).with_json(request)
+}
+
+#[derive(Serialize)]
+struct CopySheetToRequest<'a> {
+    #[serde(rename(serialize = "destinationSpreadsheetId"))]
+    destination_spreadsheet_id: &'a str,
+}
+
+/// `spreadsheets.sheets.copyTo`, shared with the async API.
+pub(crate) fn copy_sheet_to(
+    spreadsheet_id: &str,
+    sheet_id: i32,
+    destination_spreadsheet_id: &str,
+) -> Call<SheetProperties> {
+    let path = format!("spreadsheets/{}/sheets/{}:copyTo", encode_segment(spreadsheet_id), sheet_id);
+
+    Call::new(Request::new(Method::Post, path), &[SPREADSHEETS_SCOPE])
+        .with_json(&CopySheetToRequest { destination_spreadsheet_id })
+}
+
+impl Default for SheetApi {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 //<editor-fold desc="Tests">
@@ -272,5 +465,402 @@ fn test_spreadsheet_decode() {
     assert_eq!(spreadsheet.spreadsheet_id(), "1Zzq6vF93SJsd1iNnLdxtA2Yg3SP115Mtli4UYGeJD4A");
     assert_eq!(spreadsheet.spreadsheet_url(), "https://docs.google.com/spreadsheets/d/1Zzq6vF93SJsd1iNnLdxtA2Yg3SP115Mtli4UYGeJD4A/edit");
     assert_eq!(spreadsheet.sheets.len(), 1);
+
+    let format = spreadsheet.properties.default_format.as_ref().unwrap();
+    assert_eq!(format.vertical_alignment, Some(self::enums::VerticalAlignment::Bottom));
+    assert_eq!(format.wrap_strategy, Some(self::enums::WrapStrategy::OverflowCell));
+    assert_eq!(format.padding.as_ref().unwrap().right, Some(3));
+    let text = format.text_format.as_ref().unwrap();
+    assert_eq!(text.font_family.as_deref(), Some("arial,sans,sans-serif"));
+    assert_eq!(text.font_size, Some(10));
+    assert_eq!(text.bold, Some(false));
+}
+
+#[test]
+fn test_sheet_objects_decode() {
+    use self::enums::{HorizontalAlignment, RefreshScope};
+
+    let spreadsheet: Spreadsheet = serde_json::from_value(json!({
+        "spreadsheetId": "id",
+        "sheets": [{
+            "properties": {"sheetId": 0, "title": "Sheet1"},
+            "filterViews": [{"filterViewId": 1, "title": "v", "range": {"sheetId": 0}}],
+            "slicers": [{
+                "slicerId": 7,
+                "spec": {"dataRange": {"sheetId": 0, "endColumnIndex": 3}, "horizontalAlignment": "CENTER"},
+                "position": {"overlayPosition": {"anchorCell": {"sheetId": 0}}}
+            }]
+        }],
+        "dataSources": [{
+            "dataSourceId": "1080547365",
+            "sheetId": 9,
+            "spec": {"bigQuery": {"projectId": "billing", "tableSpec": {"datasetId": "sales", "tableId": "orders"}}}
+        }],
+        "dataSourceSchedules": [{"enabled": true, "refreshScope": "ALL_DATA_SOURCES", "dailySchedule": {"startTime": {"hours": 8}}}]
+    }))
+    .unwrap();
+
+    let sheet = &spreadsheet.sheets[0];
+    let view = &sheet.filter_views.as_ref().unwrap()[0];
+    assert_eq!(view.filter_view_id, Some(1));
+    assert_eq!(view.title.as_deref(), Some("v"));
+    assert_eq!(view.named_range_id, None);
+
+    let slicer = &sheet.slicers.as_ref().unwrap()[0];
+    assert_eq!(slicer.slicer_id, Some(7));
+    let spec = slicer.spec.as_ref().unwrap();
+    assert_eq!(spec.horizontal_alignment, Some(HorizontalAlignment::Center));
+    assert_eq!(spec.data_range.as_ref().unwrap().end_column_index, Some(3));
+    assert!(spec.background_color.is_none());
+
+    let source = &spreadsheet.data_sources.as_ref().unwrap()[0];
+    assert_eq!(source.sheet_id, Some(9));
+    let big_query = source.spec.as_ref().and_then(|spec| spec.big_query.as_ref()).unwrap();
+    assert_eq!(big_query.project_id.as_deref(), Some("billing"));
+    assert_eq!(big_query.table_spec.as_ref().unwrap().table_id.as_deref(), Some("orders"));
+
+    let schedule = &spreadsheet.data_source_schedules().unwrap()[0];
+    assert_eq!(schedule.refresh_scope, Some(RefreshScope::AllDataSources));
+    assert_eq!(schedule.daily_schedule.as_ref().unwrap().start_time.as_ref().unwrap().hours, Some(8));
+    assert!(schedule.next_run().is_none());
+}
+
+#[test]
+fn test_cell_data_decode() {
+    use self::enums::{DataExecutionErrorCode, DataExecutionState, ErrorType, RelativeDate};
+
+    let row: RowData = serde_json::from_value(json!({"values": [
+        {
+            "userEnteredValue": {"formulaValue": "=1/0"},
+            "effectiveValue": {"errorValue": {"type": "DIVIDE_BY_ZERO", "message": "Function DIVIDE parameter 2 cannot be zero."}},
+            "formattedValue": "#DIV/0!",
+            "userEnteredFormat": {},
+            "effectiveFormat": {"horizontalAlignment": "RIGHT"}
+        },
+        {
+            "userEnteredValue": {"stringValue": "docs"},
+            "effectiveValue": {"stringValue": "docs"},
+            "formattedValue": "docs",
+            "hyperlink": "https://developers.google.com/sheets",
+            "textFormatRuns": [{"format": {}}, {"startIndex": 2, "format": {"bold": true}}],
+            "dataValidation": {"condition": {"type": "DATE_AFTER", "values": [{"relativeDate": "PAST_WEEK"}]}}
+        },
+        {"effectiveValue": {"boolValue": true}, "formattedValue": "TRUE", "note": "checked"},
+        {
+            "pivotTable": {"source": {"sheetId": 0, "endRowIndex": 9}, "rows": [{"sourceColumnOffset": 1}]},
+            "dataSourceFormula": {"dataSourceId": "1080547365", "dataExecutionStatus": {"state": "FAILED", "errorCode": "TIMED_OUT"}}
+        }
+    ]}))
+    .unwrap();
+
+    let error = &row.values[0];
+    assert_eq!(error.user_entered_value, Some(ExtendedValue::FormulaValue("=1/0".to_string())));
+    assert!(matches!(error.effective_value(), Some(ExtendedValue::ErrorValue(e)) if e.error_type == ErrorType::DivideByZero));
+    assert_eq!(error.formatted_value(), Some("#DIV/0!"));
+    assert_eq!(
+        error.effective_format().and_then(|f| f.horizontal_alignment),
+        Some(self::enums::HorizontalAlignment::Right)
+    );
+
+    let link = &row.values[1];
+    assert_eq!(link.hyperlink(), Some("https://developers.google.com/sheets"));
+    let run = &link.text_format_runs.as_ref().unwrap()[1];
+    assert_eq!(run.start_index, Some(2));
+    assert_eq!(run.format.as_ref().unwrap().bold, Some(true));
+    let condition = &link.data_validation.as_ref().unwrap().condition;
+    assert_eq!(condition.values[0].relative_date, Some(RelativeDate::PastWeek));
+
+    assert_eq!(row.values[2].effective_value(), Some(&ExtendedValue::BoolValue(true)));
+    assert_eq!(row.values[2].note.as_deref(), Some("checked"));
+    // Read-only fields are not written back.
+    assert_eq!(serde_json::to_value(&row.values[2]).unwrap(), json!({"note": "checked"}));
+
+    let pivot = &row.values[3];
+    assert!(pivot.pivot_table().is_some());
+    let status = pivot.data_source_formula().and_then(|formula| formula.data_execution_status.as_ref()).unwrap();
+    assert_eq!(status.state, Some(DataExecutionState::Failed));
+    assert_eq!(status.error_code, Some(DataExecutionErrorCode::TimedOut));
+    // Writing the cell back leaves the pivot table alone rather than clearing it with a placeholder.
+    assert_eq!(serde_json::to_value(pivot).unwrap(), json!({}));
+}
+
+#[test]
+fn test_create() {
+    use self::enums::SheetType;
+    use crate::transport::Response;
+
+    let transport = |request: &Request| -> Result<Response> {
+        let body: serde_json::Value = serde_json::from_slice(request.body.as_ref().unwrap()).unwrap();
+        assert_eq!(request.method, Method::Post);
+        assert_eq!(request.url, "https://sheets.googleapis.com/v4/spreadsheets");
+        assert_eq!(
+            body,
+            json!({
+                "properties": {"title": "Budget", "locale": "en_US", "timeZone": "Europe/Paris"},
+                "sheets": [{"properties": {"title": "2024", "gridProperties": {"rowCount": 10, "columnCount": 4}}}],
+                "namedRanges": [{"name": "total", "range": {"sheetId": 0, "startRowIndex": 9, "endRowIndex": 10}}]
+            })
+        );
+
+        Ok(Response {
+            status: 200,
+            headers: vec![],
+            body: json!({
+                "spreadsheetId": "new-id",
+                "spreadsheetUrl": "https://docs.google.com/spreadsheets/d/new-id/edit",
+                "properties": {"title": "Budget", "locale": "en_US", "autoRecalc": "ON_CHANGE", "timeZone": "Europe/Paris"},
+                "sheets": [{"properties": {
+                    "sheetId": 1540632101, "title": "2024", "index": 0, "sheetType": "GRID",
+                    "gridProperties": {"rowCount": 10, "columnCount": 4}
+                }}],
+                "namedRanges": [{"namedRangeId": "nr1", "name": "total", "range": {"startRowIndex": 9, "endRowIndex": 10}}]
+            })
+            .to_string()
+            .into_bytes(),
+        })
+    };
+
+    let mut spreadsheet = Spreadsheet::new(SpreadsheetProperties {
+        title: Some("Budget".to_string()),
+        locale: Some("en_US".to_string()),
+        time_zone: Some("Europe/Paris".to_string()),
+        ..SpreadsheetProperties::default()
+    });
+    let mut sheet = Sheet::default();
+    sheet.properties.title = Some("2024".to_string());
+    sheet.properties.grid_properties = Some(GridProperties {
+        row_count: Some(10),
+        column_count: Some(4),
+        ..GridProperties::default()
+    });
+    spreadsheet.sheets.push(sheet);
+    spreadsheet.named_ranges = Some(vec![NamedRange {
+        named_range_id: None,
+        name: "total".to_string(),
+        range: GridRange {
+            start_row_index: Some(9),
+            end_row_index: Some(10),
+            ..GridRange::default()
+        },
+    }]);
+
+    let created = Client::new(Version::V4).with_transport(transport).sheet().create(&spreadsheet).unwrap();
+    let properties = &created.sheets[0].properties;
+
+    assert_eq!(created.spreadsheet_id(), "new-id");
+    assert_eq!(created.spreadsheet_url(), "https://docs.google.com/spreadsheets/d/new-id/edit");
+    assert_eq!(properties.sheet_id, Some(1540632101));
+    assert_eq!(properties.sheet_type, Some(SheetType::Grid));
+    assert_eq!(created.named_ranges.unwrap()[0].range.sheet_id, 0);
+}
+
+#[test]
+fn test_get() {
+    use crate::transport::Response;
+
+    let transport = |request: &Request| -> Result<Response> {
+        assert_eq!(request.method, Method::Get);
+        assert_eq!(
+            request.url,
+            "https://sheets.googleapis.com/v4/spreadsheets/id?ranges=Taxes%21A1%3AB3&ranges=Notes&includeGridData=true\
+             &fields=sheets%28properties.title%2Cdata%29"
+        );
+
+        Ok(Response {
+            status: 200,
+            headers: vec![],
+            body: json!({
+                "sheets": [{
+                    "properties": {"title": "Taxes"},
+                    "data": [{
+                        "rowMetadata": [{"pixelSize": 21}, {"pixelSize": 21, "hiddenByUser": true}, {"pixelSize": 21}],
+                        "columnMetadata": [{"pixelSize": 100}, {"pixelSize": 100}],
+                        "rowData": [
+                            {"values": [{"formattedValue": "Year"}, {"formattedValue": "Amount"}]},
+                            {},
+                            {"values": [{"formattedValue": "2024"}, {"formattedValue": "$1,200.00"}]}
+                        ]
+                    }]
+                }, {
+                    "properties": {"title": "Notes"},
+                    "data": [{"startRow": 4, "startColumn": 1, "columnMetadata": [{"pixelSize": 100}]}]
+                }]
+            })
+            .to_string()
+            .into_bytes(),
+        })
+    };
+
+    let request = GetRequest::new()
+        .with_range("Taxes!A1:B3")
+        .with_ranges(vec!["Notes"])
+        .with_grid_data(true)
+        .with_fields("sheets(properties.title,data)");
+    let spreadsheet = Client::new(Version::V4).with_transport(transport).sheet().get("id", &request).unwrap();
+
+    let taxes = &spreadsheet.sheets[0].data.as_ref().unwrap()[0];
+    assert_eq!(spreadsheet.sheets[0].properties.title.as_deref(), Some("Taxes"));
+    assert_eq!(taxes.row_metadata[1].hidden_by_user, Some(true));
+    assert_eq!(taxes.row_data[0].values[1].formatted_value(), Some("Amount"));
+    assert!(taxes.row_data[1].values.is_empty());
+    assert_eq!(taxes.row_data[2].values[1].formatted_value(), Some("$1,200.00"));
+
+    let notes = &spreadsheet.sheets[1].data.as_ref().unwrap()[0];
+    assert_eq!((notes.start_row, notes.start_column), (4, 1));
+    assert!(notes.row_data.is_empty());
+}
+
+#[test]
+fn test_get_by_data_filter() {
+    use crate::rate_limit::{Operation, DEFAULT_REQUESTS_PER_MINUTE};
+    use crate::transport::Response;
+
+    let transport = |request: &Request| -> Result<Response> {
+        let body: serde_json::Value = serde_json::from_slice(request.body.as_ref().unwrap()).unwrap();
+        assert_eq!(request.method, Method::Post);
+        assert_eq!(request.url, "https://sheets.googleapis.com/v4/spreadsheets/id:getByDataFilter");
+        assert_eq!(body, json!({"dataFilters": [{"a1Range": "Taxes!A1:B1"}], "includeGridData": true}));
+
+        Ok(Response {
+            status: 200,
+            headers: vec![],
+            body: json!({
+                "spreadsheetId": "id",
+                "sheets": [{"properties": {"title": "Taxes"}, "data": [{"rowData": [{"values": [{"formattedValue": "Year"}]}]}]}]
+            })
+            .to_string()
+            .into_bytes(),
+        })
+    };
+    let client = Client::new(Version::V4).with_transport(transport);
+
+    let request = GetByDataFilterRequest::new()
+        .with_data_filter(DataFilter::A1Range("Taxes!A1:B1".to_string()))
+        .with_grid_data(true);
+    let spreadsheet = client.sheet().get_by_data_filter("id", &request).unwrap();
+    let data = spreadsheet.sheets[0].data.as_ref().unwrap();
+    assert_eq!(data[0].row_data[0].values[0].formatted_value(), Some("Year"));
+
+    // A lookup, even though it's a POST.
+    let limiter = client.rate_limiter().unwrap();
+    assert!(limiter.level(Operation::Read) < f64::from(DEFAULT_REQUESTS_PER_MINUTE));
+    assert!(limiter.level(Operation::Write) > f64::from(DEFAULT_REQUESTS_PER_MINUTE) - 0.5);
+}
+
+#[test]
+fn test_batch_update() {
+    use self::requests::{Response as Reply, UpdateSpreadsheetPropertiesRequest};
+    use crate::transport::Response;
+
+    let transport = |request: &Request| -> Result<Response> {
+        let body: serde_json::Value = serde_json::from_slice(request.body.as_ref().unwrap()).unwrap();
+        assert_eq!(request.method, Method::Post);
+        assert_eq!(request.url, "https://sheets.googleapis.com/v4/spreadsheets/id:batchUpdate");
+        assert_eq!(
+            body,
+            json!({
+                "requests": [{"updateSpreadsheetProperties": {"properties": {"timeZone": "Europe/Berlin"}, "fields": "timeZone"}}],
+                "includeSpreadsheetInResponse": true,
+                "responseRanges": ["Taxes!A1"],
+                "responseIncludeGridData": true
+            })
+        );
+
+        Ok(Response {
+            status: 200,
+            headers: vec![],
+            body: json!({
+                "spreadsheetId": "id",
+                "replies": [{}],
+                "updatedSpreadsheet": {"spreadsheetId": "id", "properties": {"timeZone": "Europe/Berlin"}}
+            })
+            .to_string()
+            .into_bytes(),
+        })
+    };
+
+    let properties = SpreadsheetProperties {
+        time_zone: Some("Europe/Berlin".to_string()),
+        ..SpreadsheetProperties::default()
+    };
+    let request = BatchUpdateSpreadsheetRequest::new()
+        .with_request(UpdateSpreadsheetPropertiesRequest::new(properties, "timeZone"))
+        .with_spreadsheet_in_response(true)
+        .with_response_range("Taxes!A1")
+        .with_response_grid_data(true);
+    let response = Client::new(Version::V4).with_transport(transport).sheet().batch_update("id", &request).unwrap();
+
+    assert_eq!(response.replies, vec![Reply::Other(serde_json::Map::new())]);
+    let updated = response.updated_spreadsheet.unwrap();
+    assert_eq!(updated.properties.time_zone.as_deref(), Some("Europe/Berlin"));
+}
+
+#[test]
+fn test_copy_sheet_to() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use crate::rate_limit::{Operation, RateLimiter};
+    use crate::retry::RetryPolicy;
+    use crate::transport::Response;
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let transport = {
+        let calls = calls.clone();
+        move |request: &Request| -> Result<Response> {
+            assert_eq!(request.method, Method::Post);
+            assert_eq!(request.url, "https://sheets.googleapis.com/v4/spreadsheets/template/sheets/0:copyTo");
+            assert_eq!(request.body.as_deref(), Some(br#"{"destinationSpreadsheetId":"client-42"}"#.as_ref()));
+
+            let (status, body) = match calls.fetch_add(1, Ordering::SeqCst) {
+                0 => (503, json!({"error": {"code": 503, "message": "Unavailable", "status": "UNAVAILABLE"}})),
+                _ => (200, json!({"sheetId": 1771, "title": "Copy of Report", "index": 3, "sheetType": "GRID",
+                    "gridProperties": {"rowCount": 1000, "columnCount": 26}})),
+            };
+            Ok(Response {
+                status,
+                headers: vec![],
+                body: body.to_string().into_bytes(),
+            })
+        }
+    };
+    let client = Client::new(Version::V4)
+        .with_transport(transport)
+        .with_rate_limiter(RateLimiter::with_limits(60, 10));
+
+    // A copy is not idempotent: it's only retried when allowed.
+    assert_eq!(client.sheet().copy_sheet_to("template", 0, "client-42").unwrap_err().status(), Some(503));
+    assert_eq!(calls.swap(0, Ordering::SeqCst), 1);
+
+    let client = client.with_retry_policy(
+        RetryPolicy::new().with_base_delay(Duration::from_millis(1)).with_non_idempotent(true),
+    );
+    let copy = client.sheet().copy_sheet_to("template", 0, "client-42").unwrap();
+    assert_eq!((copy.sheet_id, copy.title.as_deref()), (Some(1771), Some("Copy of Report")));
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    assert!(client.rate_limiter().unwrap().level(Operation::Write) < 7.1);
+}
+
+#[test]
+fn test_paths_encode_spreadsheet_id() {
+    let id = "a/b?c#d";
+
+    assert_eq!(get(id, &GetRequest::new()).request.url, "spreadsheets/a%2Fb%3Fc%23d");
+    assert_eq!(get_by_data_filter(id, &GetByDataFilterRequest::new()).request.url, "spreadsheets/a%2Fb%3Fc%23d:getByDataFilter");
+    assert_eq!(batch_update(id, &BatchUpdateSpreadsheetRequest::new()).request.url, "spreadsheets/a%2Fb%3Fc%23d:batchUpdate");
+    assert_eq!(copy_sheet_to(id, 0, "other").request.url, "spreadsheets/a%2Fb%3Fc%23d/sheets/0:copyTo");
+}
+
+#[test]
+fn test_sheet_api_shares_client() {
+    use std::sync::Arc;
+
+    let client = Client::new(Version::V4);
+    let (first, second) = (client.sheet(), client.sheet());
+
+    assert!(Arc::ptr_eq(&first.client().transport, &second.client().transport));
+    assert!(std::ptr::eq(first.client().rate_limiter().unwrap(), second.client().rate_limiter().unwrap()));
+    assert_eq!(first.client().url("spreadsheets/id"), "https://sheets.googleapis.com/v4/spreadsheets/id");
 }
 //</editor-fold">
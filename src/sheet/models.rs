@@ -1,101 +1,682 @@
-use std::fmt::{Display, Formatter};
 use serde::{Deserialize, Serialize};
 use super::enums::*;
 
 /// A type `Spreadsheet` represents a spreadsheet.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct Spreadsheet {
     /// Overall properties of a spreadsheet.
+    #[serde(default)]
     pub properties: SpreadsheetProperties,
     /// The sheets that are part of a spreadsheet.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub sheets: Vec<Sheet>,
-    #[serde(rename(serialize = "dataSources", deserialize = "dataSources"))]
+    #[serde(rename(serialize = "dataSources", deserialize = "dataSources"), skip_serializing_if = "Option::is_none")]
     /// A list of external data sources connected with the spreadsheet.
     pub data_sources: Option<Vec<DataSource>>,
-    #[serde(rename(serialize = "developerMetadata", deserialize = "developerMetadata"))]
+    #[serde(rename(serialize = "developerMetadata", deserialize = "developerMetadata"), skip_serializing_if = "Option::is_none")]
     /// The developer metadata associated with a spreadsheet..
     pub developer_metadata: Option<Vec<DeveloperMetadata>>,
-    #[serde(rename(serialize = "dataSourceSchedules", deserialize = "dataSourceSchedules"))]
+    #[serde(rename(serialize = "dataSourceSchedules", deserialize = "dataSourceSchedules"), skip_serializing)]
     //#[readonly]
     /// Output only. A list of data source refresh schedules.
     data_source_schedules: Option<Vec<DataSourceRefreshSchedule>>,
-    #[serde(rename(serialize = "spreadsheetUrl", deserialize = "spreadsheetUrl"))]
+    #[serde(rename(serialize = "spreadsheetUrl", deserialize = "spreadsheetUrl"), default, skip_serializing)]
     //#[readonly]
     /// The url of the spreadsheet. This field is read-only.
     spreadsheet_url: String,
-    #[serde(rename(serialize = "namedRanges", deserialize = "namedRanges"))]
+    #[serde(rename(serialize = "namedRanges", deserialize = "namedRanges"), skip_serializing_if = "Option::is_none")]
     /// The named ranges defined in a spreadsheet.
     pub named_ranges: Option<Vec<NamedRange>>,
-    #[serde(rename(serialize = "spreadsheetId", deserialize = "spreadsheetId"))]
+    #[serde(rename(serialize = "spreadsheetId", deserialize = "spreadsheetId"), default, skip_serializing)]
     //#[readonly]
     /// The ID of the spreadsheet. This field is read-only.
     spreadsheet_id: String,
 }
 
 impl Spreadsheet {
+    /// A spreadsheet to create, add sheets, named ranges and developer metadata through the public fields.
+    pub fn new(properties: SpreadsheetProperties) -> Self {
+        Self {
+            properties,
+            ..Self::default()
+        }
+    }
     pub fn spreadsheet_id(&self) -> String {
         self.spreadsheet_id.clone()
     }
     pub fn spreadsheet_url(&self) -> String {
         self.spreadsheet_url.clone()
     }
+    pub fn data_source_schedules(&self) -> Option<&Vec<DataSourceRefreshSchedule>> {
+        self.data_source_schedules.as_ref()
+    }
+}
+
+/// Properties of a spreadsheet.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SpreadsheetProperties {
+    /// The title of the spreadsheet.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The locale of the spreadsheet, e.g. an ISO 639-1 language code such as `en`,
+    /// or a combination of the language and country codes such as `en_US`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    /// The amount of time to wait before volatile functions are recalculated.
+    #[serde(rename(serialize = "autoRecalc", deserialize = "autoRecalc"), skip_serializing_if = "Option::is_none")]
+    pub auto_recalc: Option<RecalculationInterval>,
+    /// The time zone of the spreadsheet, in CLDR format such as `America/New_York`.
+    #[serde(rename(serialize = "timeZone", deserialize = "timeZone"), skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<String>,
+    /// The default format of all cells in the spreadsheet.
+    /// Will not be set if the cell's format is equal to this default format.
+    #[serde(rename(serialize = "defaultFormat", deserialize = "defaultFormat"), skip_serializing_if = "Option::is_none")]
+    pub default_format: Option<CellFormat>,
+    /// Determines whether and how circular references are resolved with iterative calculation.
+    /// Absence of this field means that circular references result in calculation errors.
+    #[serde(
+        rename(serialize = "iterativeCalculationSettings", deserialize = "iterativeCalculationSettings"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub iterative_calculation_settings: Option<IterativeCalculationSettings>,
+    /// Theme applied to the spreadsheet.
+    #[serde(rename(serialize = "spreadsheetTheme", deserialize = "spreadsheetTheme"), skip_serializing_if = "Option::is_none")]
+    pub spreadsheet_theme: Option<SpreadsheetTheme>,
+    /// Whether to allow external url access for image and import functions.
+    #[serde(
+        rename(serialize = "importFunctionsExternalUrlAccessAllowed", deserialize = "importFunctionsExternalUrlAccessAllowed"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub import_functions_external_url_access_allowed: Option<bool>,
+}
+
+/// Settings to control how circular dependencies are resolved with iterative calculation.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct IterativeCalculationSettings {
+    /// When iterative calculation is enabled, the maximum number of calculation rounds to perform.
+    #[serde(rename(serialize = "maxIterations", deserialize = "maxIterations"), skip_serializing_if = "Option::is_none")]
+    pub max_iterations: Option<i32>,
+    /// When iterative calculation is enabled and successive results differ by less than this threshold value,
+    /// the calculation rounds stop.
+    #[serde(rename(serialize = "convergenceThreshold", deserialize = "convergenceThreshold"), skip_serializing_if = "Option::is_none")]
+    pub convergence_threshold: Option<f64>,
+}
+
+/// Represents spreadsheet theme.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SpreadsheetTheme {
+    /// Name of the primary font family.
+    #[serde(rename(serialize = "primaryFontFamily", deserialize = "primaryFontFamily"), skip_serializing_if = "Option::is_none")]
+    pub primary_font_family: Option<String>,
+    /// The spreadsheet theme color pairs.
+    /// To update you must provide all theme color pairs.
+    #[serde(rename(serialize = "themeColors", deserialize = "themeColors"), default, skip_serializing_if = "Vec::is_empty")]
+    pub theme_colors: Vec<ThemeColorPair>,
+}
+
+/// A pair mapping a spreadsheet theme color type to the concrete color it represents.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ThemeColorPair {
+    /// The type of the spreadsheet theme color.
+    #[serde(rename(serialize = "colorType", deserialize = "colorType"))]
+    pub color_type: ThemeColor,
+    /// The concrete color corresponding to the theme color type.
+    pub color: ColorStyle,
+}
+
+/// Properties of a sheet.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SheetProperties {
+    /// The ID of the sheet. Must be non-negative. This field cannot be changed once set.
+    #[serde(rename(serialize = "sheetId", deserialize = "sheetId"), skip_serializing_if = "Option::is_none")]
+    pub sheet_id: Option<i32>,
+    /// The name of the sheet.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The index of the sheet within the spreadsheet.
+    /// When adding or updating sheet properties, if this field is excluded then the sheet is added
+    /// or moved to the end of the sheet list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<i32>,
+    /// The type of sheet. Defaults to `GRID`. This field cannot be changed once set.
+    #[serde(rename(serialize = "sheetType", deserialize = "sheetType"), skip_serializing_if = "Option::is_none")]
+    pub sheet_type: Option<SheetType>,
+    /// Additional properties of the sheet if this sheet is a grid.
+    /// When writing it is an error to set any grid properties on non-grid sheets.
+    #[serde(rename(serialize = "gridProperties", deserialize = "gridProperties"), skip_serializing_if = "Option::is_none")]
+    pub grid_properties: Option<GridProperties>,
+    /// True if the sheet is hidden in the UI, false if it's visible.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hidden: Option<bool>,
+    /// The color of the tab in the UI. Deprecated: Use `tab_color_style`.
+    #[serde(rename(serialize = "tabColor", deserialize = "tabColor"), skip_serializing_if = "Option::is_none")]
+    pub tab_color: Option<Color>,
+    /// The color of the tab in the UI. If `tab_color` is also set, this field takes precedence.
+    #[serde(rename(serialize = "tabColorStyle", deserialize = "tabColorStyle"), skip_serializing_if = "Option::is_none")]
+    pub tab_color_style: Option<ColorStyle>,
+    /// True if the sheet is an RTL sheet instead of an LTR sheet.
+    #[serde(rename(serialize = "rightToLeft", deserialize = "rightToLeft"), skip_serializing_if = "Option::is_none")]
+    pub right_to_left: Option<bool>,
+    /// Output only. If present, the field contains `DATA_SOURCE` sheet specific properties.
+    #[serde(rename(serialize = "dataSourceSheetProperties", deserialize = "dataSourceSheetProperties"), skip_serializing)]
+    //#[readonly]
+    data_source_sheet_properties: Option<DataSourceSheetProperties>,
+}
+
+impl SheetProperties {
+    pub fn data_source_sheet_properties(&self) -> Option<&DataSourceSheetProperties> {
+        self.data_source_sheet_properties.as_ref()
+    }
 }
 
-#[derive(Serialize, Deserialize)]
-pub struct SpreadsheetProperties {}
+/// Properties of a grid.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct GridProperties {
+    /// The number of rows in the grid.
+    #[serde(rename(serialize = "rowCount", deserialize = "rowCount"), skip_serializing_if = "Option::is_none")]
+    pub row_count: Option<i32>,
+    /// The number of columns in the grid.
+    #[serde(rename(serialize = "columnCount", deserialize = "columnCount"), skip_serializing_if = "Option::is_none")]
+    pub column_count: Option<i32>,
+    /// The number of rows that are frozen in the grid.
+    #[serde(rename(serialize = "frozenRowCount", deserialize = "frozenRowCount"), skip_serializing_if = "Option::is_none")]
+    pub frozen_row_count: Option<i32>,
+    /// The number of columns that are frozen in the grid.
+    #[serde(rename(serialize = "frozenColumnCount", deserialize = "frozenColumnCount"), skip_serializing_if = "Option::is_none")]
+    pub frozen_column_count: Option<i32>,
+    /// True if the grid isn't showing gridlines in the UI.
+    #[serde(rename(serialize = "hideGridlines", deserialize = "hideGridlines"), skip_serializing_if = "Option::is_none")]
+    pub hide_gridlines: Option<bool>,
+    /// True if the row grouping control toggle is shown after the group.
+    #[serde(rename(serialize = "rowGroupControlAfter", deserialize = "rowGroupControlAfter"), skip_serializing_if = "Option::is_none")]
+    pub row_group_control_after: Option<bool>,
+    /// True if the column grouping control toggle is shown after the group.
+    #[serde(
+        rename(serialize = "columnGroupControlAfter", deserialize = "columnGroupControlAfter"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub column_group_control_after: Option<bool>,
+}
 
-#[derive(Serialize, Deserialize)]
-pub struct SheetProperties {}
+/// Additional properties of a `DATA_SOURCE` sheet.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DataSourceSheetProperties {
+    /// ID of the `DataSource` the sheet is connected to.
+    #[serde(rename(serialize = "dataSourceId", deserialize = "dataSourceId"))]
+    pub data_source_id: Option<String>,
+    /// The columns displayed on the sheet, corresponding to the values in `RowData`.
+    #[serde(default)]
+    pub columns: Vec<DataSourceColumn>,
+}
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ProtectedRange {}
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BasicFilter {}
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct EmbeddedChart {}
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ConditionalFormatRule {}
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct DimensionGroup {}
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BandedRange {}
 
-#[derive(Serialize, Deserialize)]
-pub struct CellData {}
+/// Data about a specific cell.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CellData {
+    /// The value the user entered in the cell, e.g. `1234`, `'Hello'` or `=NOW()`.
+    /// Dates, times and datetimes are represented as doubles in serial number format.
+    #[serde(rename(serialize = "userEnteredValue", deserialize = "userEnteredValue"), skip_serializing_if = "Option::is_none")]
+    pub user_entered_value: Option<ExtendedValue>,
+
+    /// The effective value of the cell, e.g. the result of its formula. This field is read-only.
+    #[serde(rename(serialize = "effectiveValue", deserialize = "effectiveValue"), skip_serializing)]
+    //#[readonly]
+    effective_value: Option<ExtendedValue>,
+
+    /// The formatted value of the cell, as it's shown to the user. This field is read-only.
+    #[serde(rename(serialize = "formattedValue", deserialize = "formattedValue"), skip_serializing)]
+    //#[readonly]
+    formatted_value: Option<String>,
+
+    /// The format the user entered for the cell.
+    #[serde(rename(serialize = "userEnteredFormat", deserialize = "userEnteredFormat"), skip_serializing_if = "Option::is_none")]
+    pub user_entered_format: Option<CellFormat>,
+
+    /// The format used by the cell, i.e. the user entered format combined with conditional formatting.
+    /// This field is read-only.
+    #[serde(rename(serialize = "effectiveFormat", deserialize = "effectiveFormat"), skip_serializing)]
+    //#[readonly]
+    effective_format: Option<CellFormat>,
+
+    /// The hyperlink this cell points to, if any. This field is read-only, set it with a `=HYPERLINK` formula
+    /// or a link in `text_format_runs`.
+    #[serde(skip_serializing)]
+    //#[readonly]
+    hyperlink: Option<String>,
+
+    /// Any note on the cell.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+
+    /// Runs of rich text applied to subsections of the cell, only valid on user entered strings.
+    /// When writing, the new runs overwrite all prior ones.
+    #[serde(rename(serialize = "textFormatRuns", deserialize = "textFormatRuns"), skip_serializing_if = "Option::is_none")]
+    pub text_format_runs: Option<Vec<TextFormatRun>>,
+
+    /// A data validation rule on the cell, if any. When writing, the new rule overwrites any prior one.
+    #[serde(rename(serialize = "dataValidation", deserialize = "dataValidation"), skip_serializing_if = "Option::is_none")]
+    pub data_validation: Option<DataValidationRule>,
+
+    /// A pivot table anchored at this cell. Read-only until pivot tables are modelled,
+    /// since writing the empty placeholder back would remove the pivot table.
+    #[serde(rename(serialize = "pivotTable", deserialize = "pivotTable"), skip_serializing)]
+    //#[readonly]
+    pivot_table: Option<PivotTable>,
 
-#[derive(Serialize, Deserialize)]
+    /// A data source table anchored at this cell. Read-only until data source tables are modelled,
+    /// since writing the empty placeholder back would remove the table.
+    #[serde(rename(serialize = "dataSourceTable", deserialize = "dataSourceTable"), skip_serializing)]
+    //#[readonly]
+    data_source_table: Option<DataSourceTable>,
+
+    /// Output only. Information about a data source formula on the cell.
+    #[serde(rename(serialize = "dataSourceFormula", deserialize = "dataSourceFormula"), skip_serializing)]
+    //#[readonly]
+    data_source_formula: Option<DataSourceFormula>,
+}
+
+impl CellData {
+    /// A cell to write with `value`, e.g. `ExtendedValue::FormulaValue("=SUM(A1:A9)".into())`.
+    pub fn new(value: ExtendedValue) -> Self {
+        Self {
+            user_entered_value: Some(value),
+            ..Self::default()
+        }
+    }
+    pub fn effective_value(&self) -> Option<&ExtendedValue> {
+        self.effective_value.as_ref()
+    }
+    pub fn formatted_value(&self) -> Option<&str> {
+        self.formatted_value.as_deref()
+    }
+    pub fn effective_format(&self) -> Option<&CellFormat> {
+        self.effective_format.as_ref()
+    }
+    pub fn hyperlink(&self) -> Option<&str> {
+        self.hyperlink.as_deref()
+    }
+    pub fn pivot_table(&self) -> Option<&PivotTable> {
+        self.pivot_table.as_ref()
+    }
+    pub fn data_source_table(&self) -> Option<&DataSourceTable> {
+        self.data_source_table.as_ref()
+    }
+    pub fn data_source_formula(&self) -> Option<&DataSourceFormula> {
+        self.data_source_formula.as_ref()
+    }
+}
+
+/// The kinds of value a cell may have, sent as an object with one field named after the kind.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ExtendedValue {
+    /// A double. Dates, times and datetimes are doubles in serial number format.
+    #[serde(rename(serialize = "numberValue", deserialize = "numberValue"))]
+    NumberValue(f64),
+    /// A string. Leading single quotes are not needed, e.g. `'123` is written as `123`.
+    #[serde(rename(serialize = "stringValue", deserialize = "stringValue"))]
+    StringValue(String),
+    #[serde(rename(serialize = "boolValue", deserialize = "boolValue"))]
+    BoolValue(bool),
+    /// A formula, starting with `=`.
+    #[serde(rename(serialize = "formulaValue", deserialize = "formulaValue"))]
+    FormulaValue(String),
+    /// An error, only read and never written.
+    #[serde(rename(serialize = "errorValue", deserialize = "errorValue"))]
+    ErrorValue(ErrorValue),
+}
+
+/// An error in a cell.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorValue {
+    /// The type of error.
+    #[serde(rename(serialize = "type", deserialize = "type"))]
+    pub error_type: ErrorType,
+    /// A message with more information about the error, in the spreadsheet's locale.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// A run of a text format, up to the start of the next run or the end of the cell.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TextFormatRun {
+    /// The zero-based character index where this run starts, in UTF-16 code units.
+    #[serde(rename(serialize = "startIndex", deserialize = "startIndex"), skip_serializing_if = "Option::is_none")]
+    pub start_index: Option<i32>,
+    /// The format of this run. Absent values inherit the cell's format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<TextFormat>,
+}
+
+/// A data validation rule.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DataValidationRule {
+    /// The condition that data in the cell must match.
+    pub condition: BooleanCondition,
+    /// A message to show the user when adding data to the cell.
+    #[serde(rename(serialize = "inputMessage", deserialize = "inputMessage"), skip_serializing_if = "Option::is_none")]
+    pub input_message: Option<String>,
+    /// True if invalid data should be rejected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strict: Option<bool>,
+    /// True if the UI should be customized based on the kind of condition, e.g. a dropdown for `ONE_OF_LIST`.
+    #[serde(rename(serialize = "showCustomUi", deserialize = "showCustomUi"), skip_serializing_if = "Option::is_none")]
+    pub show_custom_ui: Option<bool>,
+}
+
+/// A condition that can evaluate to true or false.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BooleanCondition {
+    /// The type of condition.
+    #[serde(rename(serialize = "type", deserialize = "type"))]
+    pub condition_type: ConditionType,
+    /// The values of the condition, as many as the condition type requires.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub values: Vec<ConditionValue>,
+}
+
+/// The value of a condition, exactly one of `relative_date` and `user_entered_value` being set.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConditionValue {
+    /// A relative date, only valid with date conditions.
+    #[serde(rename(serialize = "relativeDate", deserialize = "relativeDate"), skip_serializing_if = "Option::is_none")]
+    pub relative_date: Option<RelativeDate>,
+    /// A value the condition is based on, parsed as if the user typed it into a cell. Formulas start with `=`.
+    #[serde(rename(serialize = "userEnteredValue", deserialize = "userEnteredValue"), skip_serializing_if = "Option::is_none")]
+    pub user_entered_value: Option<String>,
+}
+
+/// A pivot table, not modelled yet: only its presence in a cell is known.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PivotTable {}
+
+/// A data source table, not modelled yet: only its presence in a cell is known.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DataSourceTable {}
+
+/// A formula connected to a data source.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DataSourceFormula {
+    /// The ID of the data source the formula is associated with.
+    #[serde(rename(serialize = "dataSourceId", deserialize = "dataSourceId"), skip_serializing_if = "Option::is_none")]
+    pub data_source_id: Option<String>,
+    /// Output only. The data execution status.
+    #[serde(rename(serialize = "dataExecutionStatus", deserialize = "dataExecutionStatus"), skip_serializing_if = "Option::is_none")]
+    pub data_execution_status: Option<DataExecutionStatus>,
+}
+
+/// The status of the data execution of a data source object, e.g. the refresh of a data source formula.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DataExecutionStatus {
+    /// The state of the data execution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<DataExecutionState>,
+    /// The error code, if the execution failed.
+    #[serde(rename(serialize = "errorCode", deserialize = "errorCode"), skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<DataExecutionErrorCode>,
+    /// The error message, which may be empty.
+    #[serde(rename(serialize = "errorMessage", deserialize = "errorMessage"), skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+    /// When the data was last successfully refreshed, a RFC 3339 timestamp.
+    #[serde(rename(serialize = "lastRefreshTime", deserialize = "lastRefreshTime"), skip_serializing_if = "Option::is_none")]
+    pub last_refresh_time: Option<String>,
+}
+
+/// A coordinate on a sheet. All indexes are zero-based.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GridCoordinate {
+    /// The sheet this coordinate is on.
+    #[serde(rename(serialize = "sheetId", deserialize = "sheetId"), default)]
+    pub sheet_id: i32,
+    /// The row index of the coordinate.
+    #[serde(rename(serialize = "rowIndex", deserialize = "rowIndex"), default)]
+    pub row_index: i32,
+    /// The column index of the coordinate.
+    #[serde(rename(serialize = "columnIndex", deserialize = "columnIndex"), default)]
+    pub column_index: i32,
+}
+
+impl GridCoordinate {
+    pub fn new(sheet_id: i32, row_index: i32, column_index: i32) -> Self {
+        Self {
+            sheet_id,
+            row_index,
+            column_index,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct EmbeddedObjectPosition {}
 
-#[derive(Serialize, Deserialize)]
-pub struct TextFormat {}
+/// The format of a cell. Absent fields are inherited from the sheet's default format.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CellFormat {
+    /// A format describing how number values should be represented to the user.
+    #[serde(rename(serialize = "numberFormat", deserialize = "numberFormat"), skip_serializing_if = "Option::is_none")]
+    pub number_format: Option<NumberFormat>,
+    /// Deprecated: Use `background_color_style`.
+    #[serde(rename(serialize = "backgroundColor", deserialize = "backgroundColor"), skip_serializing_if = "Option::is_none")]
+    pub background_color: Option<Color>,
+    /// The background color of the cell.
+    /// If background_color is also set, this field takes precedence.
+    #[serde(rename(serialize = "backgroundColorStyle", deserialize = "backgroundColorStyle"), skip_serializing_if = "Option::is_none")]
+    pub background_color_style: Option<ColorStyle>,
+    /// The borders of the cell.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub borders: Option<Borders>,
+    /// The padding of the cell.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub padding: Option<Padding>,
+    /// The horizontal alignment of the value in the cell.
+    #[serde(rename(serialize = "horizontalAlignment", deserialize = "horizontalAlignment"), skip_serializing_if = "Option::is_none")]
+    pub horizontal_alignment: Option<HorizontalAlignment>,
+    /// The vertical alignment of the value in the cell.
+    #[serde(rename(serialize = "verticalAlignment", deserialize = "verticalAlignment"), skip_serializing_if = "Option::is_none")]
+    pub vertical_alignment: Option<VerticalAlignment>,
+    /// The wrap strategy for the value in the cell.
+    #[serde(rename(serialize = "wrapStrategy", deserialize = "wrapStrategy"), skip_serializing_if = "Option::is_none")]
+    pub wrap_strategy: Option<WrapStrategy>,
+    /// The direction of the text in the cell.
+    #[serde(rename(serialize = "textDirection", deserialize = "textDirection"), skip_serializing_if = "Option::is_none")]
+    pub text_direction: Option<TextDirection>,
+    /// The format of the text in the cell, unless overridden by a format run.
+    #[serde(rename(serialize = "textFormat", deserialize = "textFormat"), skip_serializing_if = "Option::is_none")]
+    pub text_format: Option<TextFormat>,
+    /// If one exists, how a hyperlink should be displayed in the cell.
+    #[serde(rename(serialize = "hyperlinkDisplayType", deserialize = "hyperlinkDisplayType"), skip_serializing_if = "Option::is_none")]
+    pub hyperlink_display_type: Option<HyperlinkDisplayType>,
+    /// The rotation applied to text in the cell.
+    #[serde(rename(serialize = "textRotation", deserialize = "textRotation"), skip_serializing_if = "Option::is_none")]
+    pub text_rotation: Option<TextRotation>,
+}
+
+/// The number format of a cell.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NumberFormat {
+    /// The type of the number format. When writing, this field must be set.
+    #[serde(rename(serialize = "type", deserialize = "type"))]
+    pub number_format_type: NumberFormatType,
+    /// Pattern string used for formatting, e.g. `#,##0.00` or `yyyy-mm-dd`.
+    /// If not set, a default pattern based on the user's locale is used if necessary for the given type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+}
+
+impl NumberFormat {
+    pub fn new(number_format_type: NumberFormatType) -> Self {
+        Self {
+            number_format_type,
+            pattern: None,
+        }
+    }
+
+    pub fn with_pattern<S: Into<String>>(mut self, pattern: S) -> Self {
+        self.pattern = Some(pattern.into());
+        self
+    }
+}
+
+/// The borders of a cell.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Borders {
+    /// The top border of the cell.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top: Option<Border>,
+    /// The bottom border of the cell.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bottom: Option<Border>,
+    /// The left border of the cell.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub left: Option<Border>,
+    /// The right border of the cell.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub right: Option<Border>,
+}
+
+/// A border along a cell.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Border {
+    /// The style of the border.
+    pub style: BorderStyle,
+    /// Deprecated: The width is determined by the `style` field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<i32>,
+    /// Deprecated: Use `color_style`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<Color>,
+    /// The color of the border.
+    /// If color is also set, this field takes precedence.
+    #[serde(rename(serialize = "colorStyle", deserialize = "colorStyle"), skip_serializing_if = "Option::is_none")]
+    pub color_style: Option<ColorStyle>,
+}
+
+impl Border {
+    pub fn new(style: BorderStyle) -> Self {
+        Self {
+            style,
+            width: None,
+            color: None,
+            color_style: None,
+        }
+    }
+
+    pub fn with_color_style(mut self, color_style: ColorStyle) -> Self {
+        self.color_style = Some(color_style);
+        self
+    }
+}
+
+/// The amount of padding around the cell, in pixels.
+/// Sides without padding are omitted by the API.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Padding {
+    /// The top padding of the cell.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top: Option<i32>,
+    /// The right padding of the cell.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub right: Option<i32>,
+    /// The bottom padding of the cell.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bottom: Option<i32>,
+    /// The left padding of the cell.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub left: Option<i32>,
+}
+
+/// The rotation applied to text in a cell, either an angle or vertically stacked characters.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextRotation {
+    /// The angle between the standard orientation and the desired orientation, in degrees between -90 and 90.
+    /// Positive angles are angled upwards, negative are angled downwards.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub angle: Option<i32>,
+    /// If true, text reads top to bottom, but the orientation of individual characters is unchanged.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vertical: Option<bool>,
+}
+
+/// The format of a run of text in a cell. Absent fields are inherited.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TextFormat {
+    /// Deprecated: Use `foreground_color_style`.
+    #[serde(rename(serialize = "foregroundColor", deserialize = "foregroundColor"), skip_serializing_if = "Option::is_none")]
+    pub foreground_color: Option<Color>,
+    /// The foreground color of the text.
+    /// If foreground_color is also set, this field takes precedence.
+    #[serde(rename(serialize = "foregroundColorStyle", deserialize = "foregroundColorStyle"), skip_serializing_if = "Option::is_none")]
+    pub foreground_color_style: Option<ColorStyle>,
+    /// The font family.
+    #[serde(rename(serialize = "fontFamily", deserialize = "fontFamily"), skip_serializing_if = "Option::is_none")]
+    pub font_family: Option<String>,
+    /// The size of the font.
+    #[serde(rename(serialize = "fontSize", deserialize = "fontSize"), skip_serializing_if = "Option::is_none")]
+    pub font_size: Option<i32>,
+    /// True if the text is bold.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bold: Option<bool>,
+    /// True if the text is italicized.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub italic: Option<bool>,
+    /// True if the text has a strikethrough.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strikethrough: Option<bool>,
+    /// True if the text is underlined.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub underline: Option<bool>,
+    /// The link destination of the text, if any.
+    /// Setting a link in a run of text clears the cell's existing links or a cell-level link.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link: Option<Link>,
+}
 
-#[derive(Serialize, Deserialize)]
+/// An external or local reference.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Link {
+    /// The link identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct FilterCriteria {}
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct FilterSpec {}
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SortSpec {}
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BigQueryQuerySpec {}
 
-/// A color value.
-#[derive(Serialize, Deserialize)]
+/// A color value, either a theme color or an RGB color.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct ColorStyle {
     /// Theme color.
-    #[serde(rename(serialize = "themeColor", deserialize = "themeColor"))]
-    theme_color: ThemeColor,
+    #[serde(rename(serialize = "themeColor", deserialize = "themeColor"), skip_serializing_if = "Option::is_none")]
+    pub theme_color: Option<ThemeColor>,
 
     /// RGB color.
-    #[serde(rename(serialize = "rgbColor", deserialize = "rgbColor"))]
-    rgb_color: Color,
+    #[serde(rename(serialize = "rgbColor", deserialize = "rgbColor"), skip_serializing_if = "Option::is_none")]
+    pub rgb_color: Option<Color>,
 }
 
 /// Represents a color in the RGBA color space.
@@ -106,507 +687,583 @@ pub struct ColorStyle {
 /// When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5.
 /// # Example (Java)
 /// import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor* fromProto(Color* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color* toProto(UIColor* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha \u003c= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac * 255); var green = Math.floor(greenFrac * 255); var blue = Math.floor(blueFrac * 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red \u003c\u003c 16) | (green \u003c\u003c 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i \u003c missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
-#[derive(Serialize, Deserialize)]
+/// Components equal to zero are omitted by the API, hence all of them being optional.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Color {
     /// The fraction of this color that should be applied to the pixel.
     /// That is, the final pixel color is defined by the equation: `pixel color = alpha * (this color) + (1.0 - alpha) * (background color)`
     /// This means that a value of 1.0 corresponds to a solid color, whereas a value of 0.0 corresponds to a completely transparent color.
     /// This uses a wrapper message rather than a simple float scalar so that it is possible to distinguish between a default value and the value being unset.
     /// If omitted, this color object is rendered as a solid color (as if the alpha value had been explicitly given a value of 1.0).
-    alpha: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alpha: Option<f32>,
     /// The amount of red in the color as a value in the interval [0, 1].
-    red: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub red: Option<f32>,
     /// The amount of blue in the color as a value in the interval [0, 1].
-    blue: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blue: Option<f32>,
     /// The amount of green in the color as a value in the interval [0, 1].
-    green: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub green: Option<f32>,
 }
 
 /// The specifications of a slicer.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct SlicerSpec {
-    /// The background color of the slicer.
-    #[serde(rename(serialize = "backgroundColor", deserialize = "backgroundColor"))]
-    background_color: Color,
+    /// Deprecated: Use `background_color_style`.
+    #[serde(rename(serialize = "backgroundColor", deserialize = "backgroundColor"), skip_serializing_if = "Option::is_none")]
+    pub background_color: Option<Color>,
     /// The horizontal alignment of title in the slicer. If unspecified, defaults to `LEFT`
-    horizontal_alignment: HorizontalAlignment,
-
+    #[serde(rename(serialize = "horizontalAlignment", deserialize = "horizontalAlignment"), skip_serializing_if = "Option::is_none")]
+    pub horizontal_alignment: Option<HorizontalAlignment>,
     /// The column index in the data table on which the filter is applied to.
-    #[serde(rename(serialize = "columnIndex", deserialize = "columnIndex"))]
-    column_index: i32,
-
+    #[serde(rename(serialize = "columnIndex", deserialize = "columnIndex"), skip_serializing_if = "Option::is_none")]
+    pub column_index: Option<i32>,
     /// The data range of the slicer.
-    #[serde(rename(serialize = "dataRange", deserialize = "dataRange"))]
-    data_range: GridRange,
-
+    #[serde(rename(serialize = "dataRange", deserialize = "dataRange"), skip_serializing_if = "Option::is_none")]
+    pub data_range: Option<GridRange>,
     /// The background color of the slicer.
     /// If background_color is also set, this field takes precedence.
-    #[serde(rename(serialize = "backgroundColorStyle", deserialize = "backgroundColorStyle"))]
-    background_color_style: ColorStyle,
-
+    #[serde(rename(serialize = "backgroundColorStyle", deserialize = "backgroundColorStyle"), skip_serializing_if = "Option::is_none")]
+    pub background_color_style: Option<ColorStyle>,
     /// The filtering criteria of the slicer.
-    #[serde(rename(serialize = "filterCriteria", deserialize = "filterCriteria"))]
-    filter_criteria: FilterCriteria,
-
+    #[serde(rename(serialize = "filterCriteria", deserialize = "filterCriteria"), skip_serializing_if = "Option::is_none")]
+    pub filter_criteria: Option<FilterCriteria>,
     /// The title of the slicer.
-    #[serde(rename(serialize = "title", deserialize = "title"))]
-    title: String,
-
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
     /// True if the filter should apply to pivot tables. If not set, default to `True`.
-    #[serde(rename(serialize = "applyToPivotTables", deserialize = "applyToPivotTables"))]
-    apply_to_pivot_tables: bool,
-
+    #[serde(rename(serialize = "applyToPivotTables", deserialize = "applyToPivotTables"), skip_serializing_if = "Option::is_none")]
+    pub apply_to_pivot_tables: Option<bool>,
     /// The text format of title in the slicer.
     /// The link field is not supported.
-    #[serde(rename(serialize = "textFormat", deserialize = "textFormat"))]
-    text_format: TextFormat,
+    #[serde(rename(serialize = "textFormat", deserialize = "textFormat"), skip_serializing_if = "Option::is_none")]
+    pub text_format: Option<TextFormat>,
 }
 
 /// A slicer in a sheet.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct Slicer {
     /// The specification of the slicer.
-    spec: SlicerSpec,
-
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spec: Option<SlicerSpec>,
     /// The ID of the slicer.
-    #[serde(rename(serialize = "slicerId", deserialize = "slicerId"))]
-    slicer_id: i32,
+    #[serde(rename(serialize = "slicerId", deserialize = "slicerId"), skip_serializing_if = "Option::is_none")]
+    pub slicer_id: Option<i32>,
     /// The position of the slicer.
     /// Note that slicer can be positioned only on existing sheet.
     /// Also, width and height of slicer can be automatically adjusted to keep it within permitted limits.
-    position: EmbeddedObjectPosition,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<EmbeddedObjectPosition>,
 }
 
 /// Data about each cell in a row.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct RowData {
     /// The values in the row, one per column.
-    values: Vec<CellData>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub values: Vec<CellData>,
 }
 
 /// Properties about a dimension.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct DimensionProperties {
     /// True if this dimension is being filtered. This field is read-only.
-    #[serde(rename(serialize = "hiddenByFilter", deserialize = "hiddenByFilter"))]
+    #[serde(rename(serialize = "hiddenByFilter", deserialize = "hiddenByFilter"), default, skip_serializing)]
     //#[readonly]
     hidden_by_filter: bool,
 
     /// Output only. If set, this is a column in a data source sheet.
-    #[serde(rename(serialize = "dataSourceColumnReference", deserialize = "dataSourceColumnReference"))]
+    #[serde(rename(serialize = "dataSourceColumnReference", deserialize = "dataSourceColumnReference"), skip_serializing)]
     //#[readonly]
-    data_source_reference: DataSourceColumnReference,
+    data_source_reference: Option<DataSourceColumnReference>,
 
     /// The developer metadata associated with a single row or column.
-    #[serde(rename(serialize = "developerMetadata", deserialize = "developerMetadata"))]
-    developer_metadata: Option<Vec<DeveloperMetadata>>,
+    #[serde(rename(serialize = "developerMetadata", deserialize = "developerMetadata"), skip_serializing_if = "Option::is_none")]
+    pub developer_metadata: Option<Vec<DeveloperMetadata>>,
 
     /// The height (if a row) or width (if a column) of the dimension in pixels.
-    #[serde(rename(serialize = "pixelSize", deserialize = "pixelSize"))]
-    pixel_size: i32,
+    #[serde(rename(serialize = "pixelSize", deserialize = "pixelSize"), skip_serializing_if = "Option::is_none")]
+    pub pixel_size: Option<i32>,
 
     /// True if this dimension is explicitly hidden.
-    #[serde(rename(serialize = "hiddenByUser", deserialize = "hiddenByUser"))]
-    hidden_by_user: bool,
+    #[serde(rename(serialize = "hiddenByUser", deserialize = "hiddenByUser"), skip_serializing_if = "Option::is_none")]
+    pub hidden_by_user: Option<bool>,
+}
+
+impl DimensionProperties {
+    pub fn hidden_by_filter(&self) -> bool {
+        self.hidden_by_filter
+    }
+    pub fn data_source_reference(&self) -> Option<&DataSourceColumnReference> {
+        self.data_source_reference.as_ref()
+    }
 }
 
 /// Data in the grid, as well as metadata about the dimensions.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct GridData {
     /// Metadata about the requested rows in the grid, starting with the row in start_row.
-    #[serde(rename(serialize = "rowMetadata", deserialize = "rowMetadata"))]
-    row_metadata: Vec<DimensionProperties>,
+    #[serde(rename(serialize = "rowMetadata", deserialize = "rowMetadata"), default, skip_serializing_if = "Vec::is_empty")]
+    pub row_metadata: Vec<DimensionProperties>,
 
     /// The first row this GridData refers to, zero-based.
-    #[serde(rename(serialize = "startRow", deserialize = "startRow"))]
-    start_row: i32,
+    #[serde(rename(serialize = "startRow", deserialize = "startRow"), default)]
+    pub start_row: i32,
 
     /// The first column this GridData refers to, zero-based.
-    #[serde(rename(serialize = "startColumn", deserialize = "startColumn"))]
-    start_column: i32,
+    #[serde(rename(serialize = "startColumn", deserialize = "startColumn"), default)]
+    pub start_column: i32,
 
     /// Metadata about the requested columns in the grid, starting with the column in start_column.
-    #[serde(rename(serialize = "columnMetadata", deserialize = "columnMetadata"))]
-    column_metadata: Vec<DimensionProperties>,
+    #[serde(rename(serialize = "columnMetadata", deserialize = "columnMetadata"), default, skip_serializing_if = "Vec::is_empty")]
+    pub column_metadata: Vec<DimensionProperties>,
 
     /// The data in the grid, one entry per row, starting with the row in startRow.
     /// The values in RowData will correspond to columns starting at start_column.
-    #[serde(rename(serialize = "rowData", deserialize = "rowData"))]
-    row_data: Vec<RowData>,
+    /// Trailing empty rows are omitted.
+    #[serde(rename(serialize = "rowData", deserialize = "rowData"), default, skip_serializing_if = "Vec::is_empty")]
+    pub row_data: Vec<RowData>,
 }
 
 /// A filter view.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct FilterView {
     /// The named range this filter view is backed by, if any.
     /// When writing, only one of range or named_range_id may be set.
-    #[serde(rename(serialize = "namedRangeId", deserialize = "namedRangeId"))]
-    named_range_id: String,
-
+    #[serde(rename(serialize = "namedRangeId", deserialize = "namedRangeId"), skip_serializing_if = "Option::is_none")]
+    pub named_range_id: Option<String>,
     /// The range this filter view covers.
     /// When writing, only one of range or named_range_id may be set.
-    range: GridRange,
-
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub range: Option<GridRange>,
     /// The name of the filter view.
-    title: String,
-
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
     /// The filter criteria for showing/hiding values per column.
     /// Both criteria and filter_specs are populated in responses.
     /// If both fields are specified in an update request, this field takes precedence.
-    #[serde(rename(serialize = "filterSpecs", deserialize = "filterSpecs"))]
-    filter_specs: Vec<FilterSpec>,
-
+    #[serde(rename(serialize = "filterSpecs", deserialize = "filterSpecs"), default, skip_serializing_if = "Vec::is_empty")]
+    pub filter_specs: Vec<FilterSpec>,
     /// The sort order per column.
     /// Later specifications are used when values are equal in the earlier specifications.
-    #[serde(rename(serialize = "sortSpecs", deserialize = "sortSpecs"))]
-    sort_secs: Vec<SortSpec>,
-
+    #[serde(rename(serialize = "sortSpecs", deserialize = "sortSpecs"), default, skip_serializing_if = "Vec::is_empty")]
+    pub sort_specs: Vec<SortSpec>,
     /// The ID of the filter view.
-    #[serde(rename(serialize = "filterViewId", deserialize = "filterViewId"))]
-    filter_view_id: i32,
-
+    #[serde(rename(serialize = "filterViewId", deserialize = "filterViewId"), skip_serializing_if = "Option::is_none")]
+    pub filter_view_id: Option<i32>,
     /// The criteria for showing/hiding values per column.
     /// The map's key is the column index, and the value is the criteria for that column.
     /// This field is deprecated in favor of filter_specs.
-    criteria: FilterCriteria,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub criteria: Option<FilterCriteria>,
 }
 
 /// A sheet in a spreadsheet.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct Sheet {
     /// The properties of the sheet.
-    properties: SheetProperties,
+    #[serde(default)]
+    pub properties: SheetProperties,
 
     /// The protected ranges in this sheet.
-    #[serde(rename(serialize = "protectedRanges", deserialize = "protectedRanges"))]
-    protected_ranges: Option<Vec<ProtectedRange>>,
+    #[serde(rename(serialize = "protectedRanges", deserialize = "protectedRanges"), skip_serializing_if = "Option::is_none")]
+    pub protected_ranges: Option<Vec<ProtectedRange>>,
 
     /// The filter on this sheet, if any.
-    #[serde(rename(serialize = "basicFilter", deserialize = "basicFilter"))]
-    basic_filter: Option<BasicFilter>,
+    #[serde(rename(serialize = "basicFilter", deserialize = "basicFilter"), skip_serializing_if = "Option::is_none")]
+    pub basic_filter: Option<BasicFilter>,
 
     /// All row groups on this sheet, ordered by increasing range start index, then by group depth.
-    #[serde(rename(serialize = "rowGroups", deserialize = "rowGroups"))]
-    row_groups: Option<Vec<DimensionGroup>>,
+    #[serde(rename(serialize = "rowGroups", deserialize = "rowGroups"), skip_serializing_if = "Option::is_none")]
+    pub row_groups: Option<Vec<DimensionGroup>>,
 
     /// The specifications of every chart on this sheet.
-    charts: Vec<EmbeddedChart>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charts: Option<Vec<EmbeddedChart>>,
 
     /// The developer metadata associated with a sheet.
-    #[serde(rename(serialize = "developerMetadata", deserialize = "developerMetadata"))]
-    developer_metadata: Option<Vec<DeveloperMetadata>>,
+    #[serde(rename(serialize = "developerMetadata", deserialize = "developerMetadata"), skip_serializing_if = "Option::is_none")]
+    pub developer_metadata: Option<Vec<DeveloperMetadata>>,
 
     /// The ranges that are merged together.
-    merges: Option<Vec<GridRange>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merges: Option<Vec<GridRange>>,
 
     /// The conditional format rules in this sheet.
-    #[serde(rename(serialize = "conditionalFormats", deserialize = "conditionalFormats"))]
-    conditional_formats: Option<Vec<ConditionalFormatRule>>,
+    #[serde(rename(serialize = "conditionalFormats", deserialize = "conditionalFormats"), skip_serializing_if = "Option::is_none")]
+    pub conditional_formats: Option<Vec<ConditionalFormatRule>>,
 
     /// All column groups on this sheet, ordered by increasing range start index, then by group depth.
-    #[serde(rename(serialize = "columnGroups", deserialize = "columnGroups"))]
-    column_groups: Option<Vec<DimensionGroup>>,
+    #[serde(rename(serialize = "columnGroups", deserialize = "columnGroups"), skip_serializing_if = "Option::is_none")]
+    pub column_groups: Option<Vec<DimensionGroup>>,
 
     /// The banded (alternating colors) ranges on this sheet.
-    #[serde(rename(serialize = "bandedRanges", deserialize = "bandedRanges"))]
-    banded_ranges: Option<Vec<BandedRange>>,
+    #[serde(rename(serialize = "bandedRanges", deserialize = "bandedRanges"), skip_serializing_if = "Option::is_none")]
+    pub banded_ranges: Option<Vec<BandedRange>>,
 
     /// The slicers on this sheet.
-    slicers: Option<Vec<Slicer>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slicers: Option<Vec<Slicer>>,
 
     /// Data in the grid, if this is a grid sheet. The number of GridData objects returned is dependent on the number of ranges requested on this sheet.
     /// For example, if this is representing `Sheet1`, and the spreadsheet was requested with ranges `Sheet1!A1:C10` and `Sheet1!D15:E20`, then the first GridData will have a startRow/startColumn of `0`, while the second one will have `startRow 14` (zero-based row 15), and `startColumn 3` (zero-based column D).
     /// For a DATA_SOURCE sheet, you can not request a specific range, the GridData contains all the values.
-    data: Option<Vec<GridData>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Vec<GridData>>,
 
     /// The filter views in this sheet.
-    #[serde(rename(serialize = "filterViews", deserialize = "filterViews"))]
-    filter_views: Option<Vec<FilterView>>,
+    #[serde(rename(serialize = "filterViews", deserialize = "filterViews"), skip_serializing_if = "Option::is_none")]
+    pub filter_views: Option<Vec<FilterView>>,
 }
 
 /// An unique identifier that references a data source column.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct DataSourceColumnReference {
     /// The display name of the column. It should be unique within a data source.
-    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
 }
 
 /// A column in a data source.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct DataSourceColumn {
     /// The formula of the calculated column.
-    formula: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formula: Option<String>,
 
     /// The column reference.
-    reference: DataSourceColumnReference,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<DataSourceColumnReference>,
 }
 
 /// Specifies a BigQuery table definition.
 /// Only [native tables](https://cloud.google.com/bigquery/docs/tables-intro) is allowed.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct BigQueryTableSpec {
     /// The ID of a `BigQuery` project the table belongs to.
     /// If not specified, the project_id is assumed.
-    #[serde(rename(serialize = "tableProjectId", deserialize = "tableProjectId"))]
-    table_project_id: String,
+    #[serde(rename(serialize = "tableProjectId", deserialize = "tableProjectId"), skip_serializing_if = "Option::is_none")]
+    pub table_project_id: Option<String>,
 
     /// The `BigQuery` dataset id.
-    #[serde(rename(serialize = "datasetId", deserialize = "datasetId"))]
-    dataset_id: String,
+    #[serde(rename(serialize = "datasetId", deserialize = "datasetId"), skip_serializing_if = "Option::is_none")]
+    pub dataset_id: Option<String>,
 
     /// The `BigQuery` table id.
-    #[serde(rename(serialize = "tableId", deserialize = "tableId"))]
-    table_id: String,
+    #[serde(rename(serialize = "tableId", deserialize = "tableId"), skip_serializing_if = "Option::is_none")]
+    pub table_id: Option<String>,
 }
 
 /// The specification of a `BigQuery` data source that's connected to a sheet.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct BigQueryDataSourceSpec {
     /// A `BigQueryQuerySpec`.
-    #[serde(rename(serialize = "querySpec", deserialize = "querySpec"))]
-    query_spec: BigQueryQuerySpec,
+    #[serde(rename(serialize = "querySpec", deserialize = "querySpec"), skip_serializing_if = "Option::is_none")]
+    pub query_spec: Option<BigQueryQuerySpec>,
 
     /// The ID of a BigQuery enabled GCP project with a billing account attached.
     /// For any queries executed against the data source, the project is charged.
-    #[serde(rename(serialize = "projectId", deserialize = "projectId"))]
-    project_id: String,
+    #[serde(rename(serialize = "projectId", deserialize = "projectId"), skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
 
     /// A `BigQueryTableSpec`.
-    #[serde(rename(serialize = "tableSpec", deserialize = "tableSpec"))]
-    table_spec: BigQueryTableSpec,
+    #[serde(rename(serialize = "tableSpec", deserialize = "tableSpec"), skip_serializing_if = "Option::is_none")]
+    pub table_spec: Option<BigQueryTableSpec>,
 }
 
 /// A parameter in a data source's query.
 /// The parameter allows the user to pass in values from the spreadsheet into a query.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct DataSourceParameter {
     /// Named parameter.
     /// Must be a legitimate identifier for the DataSource that supports it.
     /// For example, [BigQuery identifier](https://cloud.google.com/bigquery/docs/reference/standard-sql/lexical#identifiers).
-    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
     /// ID of a NamedRange. Its size must be 1x1.
-    #[serde(rename(serialize = "namedRangeId", deserialize = "namedRangeId"))]
-    named_range_id: String,
+    #[serde(rename(serialize = "namedRangeId", deserialize = "namedRangeId"), skip_serializing_if = "Option::is_none")]
+    pub named_range_id: Option<String>,
     /// A range that contains the value of the parameter. Its size must be 1x1.
-    range: GridRange,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub range: Option<GridRange>,
 }
 
 /// This specifies the details of the data source.
 /// For example, for BigQuery, this specifies information about the BigQuery source.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct DataSourceSpec {
     /// A `BigQueryDataSourceSpec`.
-    big_query: BigQueryDataSourceSpec,
+    #[serde(rename(serialize = "bigQuery", deserialize = "bigQuery"), skip_serializing_if = "Option::is_none")]
+    pub big_query: Option<BigQueryDataSourceSpec>,
     /// The parameters of the data source, used when querying the data source.
-    params: Vec<DataSourceParameter>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parameters: Vec<DataSourceParameter>,
 }
 
 /// Information about an external data source in the spreadsheet.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct DataSource {
     /// All calculated columns in the data source.
-    #[serde(rename(serialize = "calculatedColumns", deserialize = "calculatedColumns"))]
-    calculated_columns: Vec<DataSourceColumn>,
+    #[serde(rename(serialize = "calculatedColumns", deserialize = "calculatedColumns"), default, skip_serializing_if = "Vec::is_empty")]
+    pub calculated_columns: Vec<DataSourceColumn>,
 
     /// The ID of the Sheet connected with the data source.
     /// The field cannot be changed once set.
     /// When creating a data source, an associated DATA_SOURCE sheet is also created, if the field is not specified, the ID of the created sheet will be randomly generated.
-    #[serde(rename(serialize = "sheetId", deserialize = "sheetId"))]
-    sheet_id: i32,
+    #[serde(rename(serialize = "sheetId", deserialize = "sheetId"), skip_serializing_if = "Option::is_none")]
+    pub sheet_id: Option<i32>,
 
     /// The DataSourceSpec for the data source connected with this spreadsheet.
-    spec: DataSourceSpec,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spec: Option<DataSourceSpec>,
     /// The spreadsheet-scoped unique ID that identifies the data source.
     /// Example: 1080547365.
-    #[serde(rename(serialize = "dataSourceId", deserialize = "dataSourceId"))]
-    data_source_id: String,
+    #[serde(rename(serialize = "dataSourceId", deserialize = "dataSourceId"), skip_serializing_if = "Option::is_none")]
+    pub data_source_id: Option<String>,
 }
 
 /// A range along a single dimension on a sheet.
 /// All indexes are zero-based.
 /// Indexes are half open: the start index is inclusive and the end index is exclusive.
 /// Missing indexes indicate the range is unbounded on that side.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DimensionRange {
-    /// The end (exclusive) of the span, or not set if unbounded.
-    #[serde(rename(serialize = "endIndex", deserialize = "endIndex"))]
-    end_index: i32,
     /// The sheet this span is on.
-    #[serde(rename(serialize = "sheetId", deserialize = "sheetId"))]
-    sheet_id: i32,
+    #[serde(rename(serialize = "sheetId", deserialize = "sheetId"), default)]
+    pub sheet_id: i32,
+    /// The dimension of the span.
+    pub dimension: Dimension,
     /// The start (inclusive) of the span, or not set if unbounded.
-    #[serde(rename(serialize = "startIndex", deserialize = "startIndex"))]
-    start_index: i32,
+    #[serde(rename(serialize = "startIndex", deserialize = "startIndex"), skip_serializing_if = "Option::is_none")]
+    pub start_index: Option<i32>,
+    /// The end (exclusive) of the span, or not set if unbounded.
+    #[serde(rename(serialize = "endIndex", deserialize = "endIndex"), skip_serializing_if = "Option::is_none")]
+    pub end_index: Option<i32>,
+}
+
+impl DimensionRange {
+    /// All the rows or columns of the sheet `sheet_id`, narrowed with `with_start_index` and `with_end_index`.
+    pub fn new(sheet_id: i32, dimension: Dimension) -> Self {
+        Self {
+            sheet_id,
+            dimension,
+            start_index: None,
+            end_index: None,
+        }
+    }
+
+    /// The rows `start_index` (inclusive) to `end_index` (exclusive) of the sheet `sheet_id`.
+    pub fn rows(sheet_id: i32, start_index: i32, end_index: i32) -> Self {
+        Self::new(sheet_id, Dimension::Rows).with_start_index(start_index).with_end_index(end_index)
+    }
+
+    /// The columns `start_index` (inclusive) to `end_index` (exclusive) of the sheet `sheet_id`.
+    pub fn columns(sheet_id: i32, start_index: i32, end_index: i32) -> Self {
+        Self::new(sheet_id, Dimension::Columns).with_start_index(start_index).with_end_index(end_index)
+    }
+
+    pub fn with_start_index(mut self, index: i32) -> Self {
+        self.start_index = Some(index);
+        self
+    }
+
+    pub fn with_end_index(mut self, index: i32) -> Self {
+        self.end_index = Some(index);
+        self
+    }
 }
 
 /// A location where metadata may be associated in a spreadsheet.
-#[derive(Serialize, Deserialize)]
+/// Exactly one of `dimension_range`, `spreadsheet` and `sheet_id` is set.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DeveloperMetadataLocation {
     /// Represents the row or column when metadata is associated with a dimension.
     /// The specified DimensionRange must represent a single row or column;
     /// it cannot be unbounded or span multiple rows or columns.
-    #[serde(rename(serialize = "dimensionRange", deserialize = "dimensionRange"))]
-    dimension_range: DimensionRange,
+    #[serde(rename(serialize = "dimensionRange", deserialize = "dimensionRange"), skip_serializing_if = "Option::is_none")]
+    pub dimension_range: Option<DimensionRange>,
 
     /// True when metadata is associated with an entire spreadsheet.
-    spreadsheet: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spreadsheet: Option<bool>,
 
     /// The type of location this object represents. This field is read-only.
-    #[serde(rename(serialize = "locationType", deserialize = "locationType"))]
-    location_type: LocationType, //#[readonly]
+    #[serde(rename(serialize = "locationType", deserialize = "locationType"), skip_serializing)]
+    location_type: Option<LocationType>, //#[readonly]
 
     /// The ID of the sheet when metadata is associated with an entire sheet.
-    #[serde(rename(serialize = "sheetId", deserialize = "sheetId"))]
-    sheet_id: i32,
+    #[serde(rename(serialize = "sheetId", deserialize = "sheetId"), skip_serializing_if = "Option::is_none")]
+    pub sheet_id: Option<i32>,
+}
+
+impl DeveloperMetadataLocation {
+    pub fn location_type(&self) -> Option<LocationType> {
+        self.location_type
+    }
 }
 
 /// Developer metadata associated with a location or object in a spreadsheet.
 /// Developer metadata may be used to associate arbitrary data with various parts of a spreadsheet and will remain associated at those locations as they move around and the spreadsheet is edited. For example, if developer metadata is associated with row 5 and another row is then subsequently inserted above row 5, that original metadata will still be associated with the row it was first associated with (what is now row 6). If the associated object is deleted its metadata is deleted too.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct DeveloperMetadata {
     /// The metadata key.
     /// There may be multiple metadata in a spreadsheet with the same key.
     /// Developer metadata must always have a key specified.
     #[serde(rename(serialize = "metadataKey", deserialize = "metadataKey"))]
-    metadata_key: String,
+    pub metadata_key: String,
     /// The spreadsheet-scoped unique ID that identifies the metadata.
     /// IDs may be specified when metadata is created, otherwise one will be randomly generated and assigned.
     /// Must be positive.
-    #[serde(rename(serialize = "metadataId", deserialize = "metadataId"))]
-    metadata_id: i32,
+    #[serde(rename(serialize = "metadataId", deserialize = "metadataId"), skip_serializing_if = "Option::is_none")]
+    pub metadata_id: Option<i32>,
     /// The location where the metadata is associated.
-    location: DeveloperMetadataLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<DeveloperMetadataLocation>,
     /// Data associated with the metadata's key.
-    #[serde(rename(serialize = "metadataValue", deserialize = "metadataValue"))]
-    metadata_value: String,
+    #[serde(rename(serialize = "metadataValue", deserialize = "metadataValue"), skip_serializing_if = "Option::is_none")]
+    pub metadata_value: Option<String>,
     /// The metadata visibility.
     /// Developer metadata must always have a visibility specified.
-    visibility: Visibility,
+    pub visibility: Visibility,
 }
 
 /// A schedule for data to refresh every day in a given time interval.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct DataSourceRefreshDailySchedule {
-    #[serde(rename(serialize = "startTime", deserialize = "startTime"))]
     /// The start time of a time interval in which a data source refresh is scheduled.
     /// Only `hours` part is used. The time interval size defaults to that in the Sheets editor.
-    start_time: TimeOfDay,
+    #[serde(rename(serialize = "startTime", deserialize = "startTime"), skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<TimeOfDay>,
 }
 
 /// Represents a time of day.
 /// The date and time zone are either not significant or are specified elsewhere.
 /// An API may choose to allow leap seconds.
 /// Related types are google.type.Date and `google.protobuf.Timestamp`.
-#[derive(Serialize, Deserialize)]
+/// Components equal to zero are omitted by the API, hence all of them being optional.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct TimeOfDay {
     /// Minutes of hour of day.
     /// Must be from 0 to 59.
-    minutes: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minutes: Option<i32>,
     /// Hours of day in 24 hour format.
     /// Should be from 0 to 23.
     /// An API may choose to allow the value \"24:00:00\" for scenarios like business closing time.
-    hours: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hours: Option<i32>,
     /// Seconds of minutes of the time.
     /// Must normally be from 0 to 59.
     /// An API may allow the value 60 if it allows leap-seconds.
-    seconds: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seconds: Option<i32>,
     /// Fractions of seconds in nanoseconds.
     /// Must be from 0 to 999,999,999.
-    nanos: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nanos: Option<i32>,
 }
 
 /// A monthly schedule for data to refresh on specific days in the month in a given time interval.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct DataSourceRefreshMonthlySchedule {
-    #[serde(rename(serialize = "daysOfMonth", deserialize = "daysOfMonth"))]
     /// Days of the month to refresh.
     /// Only 1-28 are supported, mapping to the 1st to the 28th day.
     /// At least one day must be specified.
-    days_of_month: Vec<i32>,
+    #[serde(rename(serialize = "daysOfMonth", deserialize = "daysOfMonth"), default, skip_serializing_if = "Vec::is_empty")]
+    pub days_of_month: Vec<i32>,
 
-    #[serde(rename(serialize = "startTime", deserialize = "startTime"))]
     /// The start time of a time interval in which a data source refresh is scheduled.
     /// Only `hours` part is used.
     /// The time interval size defaults to that in the Sheets editor.
-    start_time: TimeOfDay,
+    #[serde(rename(serialize = "startTime", deserialize = "startTime"), skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<TimeOfDay>,
 }
 
 /// A weekly schedule for data to refresh on specific days in a given time interval.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct DataSourceRefreshWeeklySchedule {
     /// The start time of a time interval in which a data source refresh is scheduled.
     /// Only `hours` part is used.
     /// The time interval size defaults to that in the Sheets editor.
-    #[serde(rename(serialize = "startTime", deserialize = "startTime"))]
-    start_time: TimeOfDay,
+    #[serde(rename(serialize = "startTime", deserialize = "startTime"), skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<TimeOfDay>,
     /// Days of the week to refresh.
     /// At least one day must be specified.
-    #[serde(rename(serialize = "dailySchedule", deserialize = "dailySchedule"))]
-    days_of_week: Vec<DayOfWeek>,
+    #[serde(rename(serialize = "daysOfWeek", deserialize = "daysOfWeek"), default, skip_serializing_if = "Vec::is_empty")]
+    pub days_of_week: Vec<DayOfWeek>,
 }
 
 /// Represents a time interval, encoded as a Timestamp start (inclusive) and a Timestamp end (exclusive).
 /// The start must be less than or equal to the end.
 /// When the start equals the end, the interval is empty (matches no time).
 /// When both start and end are unspecified, the interval matches any time.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct Interval {
-    #[serde(rename(serialize = "startTime", deserialize = "startTime"))]
-    /// Optional. Inclusive start of the interval.
+    /// Optional. Inclusive start of the interval, a RFC 3339 timestamp.
     /// If specified, a Timestamp matching this interval will have to be the same or after the start.
-    start_time: Option<String>,//google-datetime
+    #[serde(rename(serialize = "startTime", deserialize = "startTime"), skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<String>,
 
-    #[serde(rename(serialize = "endTime", deserialize = "endTime"))]
-    /// Exclusive end of the interval.
+    /// Exclusive end of the interval, a RFC 3339 timestamp.
     /// If specified, a Timestamp matching this interval will have to be before the end.
-    end_time: Option<String>,//google-datetime
+    #[serde(rename(serialize = "endTime", deserialize = "endTime"), skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<String>,
 }
 
 /// Schedule for refreshing the data source.
 /// Data sources in the spreadsheet are refreshed within a time interval.
 /// You can specify the start time by clicking the Scheduled Refresh button in the Sheets editor, but the interval is fixed at 4 hours.
 /// For example, if you specify a start time of 8am , the refresh will take place between 8am and 12pm every day.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct DataSourceRefreshSchedule {
     /// True if the refresh schedule is enabled, or false otherwise.
-    enabled: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
 
-    #[serde(rename(serialize = "dailySchedule", deserialize = "dailySchedule"))]
     /// Daily refresh schedule.
-    daily_schedule: DataSourceRefreshDailySchedule,
+    #[serde(rename(serialize = "dailySchedule", deserialize = "dailySchedule"), skip_serializing_if = "Option::is_none")]
+    pub daily_schedule: Option<DataSourceRefreshDailySchedule>,
 
-    #[serde(rename(serialize = "monthlySchedule", deserialize = "monthlySchedule"))]
     /// Monthly refresh schedule.
-    monthly_schedule: DataSourceRefreshMonthlySchedule,
+    #[serde(rename(serialize = "monthlySchedule", deserialize = "monthlySchedule"), skip_serializing_if = "Option::is_none")]
+    pub monthly_schedule: Option<DataSourceRefreshMonthlySchedule>,
 
-    #[serde(rename(serialize = "weeklySchedule", deserialize = "weeklySchedule"))]
-    //#[readonly]
     /// Output only. The time interval of the next run.
-    next_run: Interval,
+    #[serde(rename(serialize = "nextRun", deserialize = "nextRun"), skip_serializing)]
+    //#[readonly]
+    next_run: Option<Interval>,
 
-    #[serde(rename(serialize = "weeklySchedule", deserialize = "weeklySchedule"))]
     /// Weekly refresh schedule.
-    weekly_schedule: DataSourceRefreshWeeklySchedule,
+    #[serde(rename(serialize = "weeklySchedule", deserialize = "weeklySchedule"), skip_serializing_if = "Option::is_none")]
+    pub weekly_schedule: Option<DataSourceRefreshWeeklySchedule>,
 
-    #[serde(rename(serialize = "refreshScope", deserialize = "refreshScope"))]
     /// The scope of the refresh. Must be ALL_DATA_SOURCES.
-    refresh_scope: RefreshScope,
+    #[serde(rename(serialize = "refreshScope", deserialize = "refreshScope"), skip_serializing_if = "Option::is_none")]
+    pub refresh_scope: Option<RefreshScope>,
+}
+
+impl DataSourceRefreshSchedule {
+    pub fn next_run(&self) -> Option<&Interval> {
+        self.next_run.as_ref()
+    }
 }
 
 /// A named range.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct NamedRange {
-    #[serde(rename(serialize = "namedRangeId", deserialize = "namedRangeId"))]
-    /// The ID of the named range.
-    named_range_id: String,
+    #[serde(rename(serialize = "namedRangeId", deserialize = "namedRangeId"), skip_serializing_if = "Option::is_none")]
+    /// The ID of the named range, generated by the server if not set.
+    pub named_range_id: Option<String>,
 
     #[serde(rename(serialize = "name", deserialize = "name"))]
     /// The name of the named range.
-    name: String,
+    pub name: String,
 
     #[serde(rename(serialize = "range", deserialize = "range"))]
     /// The range this represents.
-    range: GridRange,
+    pub range: GridRange,
 }
 
 /// A range on a sheet. All indexes are zero-based.
@@ -616,21 +1273,285 @@ pub struct NamedRange {
 /// The start index must always be less than or equal to the end index.
 /// If the start index equals the end index, then the range is empty.
 /// Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GridRange {
-    #[serde(rename(serialize = "endColumnIndex", deserialize = "endColumnIndex"))]
+    #[serde(rename(serialize = "endColumnIndex", deserialize = "endColumnIndex"), skip_serializing_if = "Option::is_none")]
     /// The end column (exclusive) of the range, or not set if unbounded.
-    end_column_index: i32,
-    #[serde(rename(serialize = "endRowIndex", deserialize = "endRowIndex"))]
+    pub end_column_index: Option<i32>,
+    #[serde(rename(serialize = "endRowIndex", deserialize = "endRowIndex"), skip_serializing_if = "Option::is_none")]
     /// The end row (exclusive) of the range, or not set if unbounded.
-    end_row_index: i32,
-    #[serde(rename(serialize = "sheetId", deserialize = "sheetId"))]
+    pub end_row_index: Option<i32>,
+    #[serde(rename(serialize = "sheetId", deserialize = "sheetId"), default)]
     /// The sheet this range is on.
-    sheet_id: i32,
-    #[serde(rename(serialize = "startRowIndex", deserialize = "startRowIndex"))]
+    pub sheet_id: i32,
+    #[serde(rename(serialize = "startRowIndex", deserialize = "startRowIndex"), skip_serializing_if = "Option::is_none")]
     /// The start row (inclusive) of the range, or not set if unbounded.
-    start_row_index: i32,
-    #[serde(rename(serialize = "startColumnIndex", deserialize = "startColumnIndex"))]
+    pub start_row_index: Option<i32>,
+    #[serde(rename(serialize = "startColumnIndex", deserialize = "startColumnIndex"), skip_serializing_if = "Option::is_none")]
     /// The start column (inclusive) of the range, or not set if unbounded.
-    start_column_index: i32,
+    pub start_column_index: Option<i32>,
+}
+
+/// Data within a range of the spreadsheet.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ValueRange {
+    /// The range the values cover, in A1 notation.
+    /// When writing, it must match the range of the call or be unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub range: Option<String>,
+    /// The major dimension of the values, `ROWS` if unset.
+    #[serde(rename(serialize = "majorDimension", deserialize = "majorDimension"), skip_serializing_if = "Option::is_none")]
+    pub major_dimension: Option<Dimension>,
+    /// The data, one list per row (or column). Strings, numbers and booleans are accepted, and `null`
+    /// skips a cell when writing. Trailing empty rows and columns are omitted when reading.
+    #[serde(default)]
+    pub values: Vec<Vec<serde_json::Value>>,
+}
+
+impl ValueRange {
+    /// Rows of values, with the range taken from the call.
+    pub fn new(values: Vec<Vec<serde_json::Value>>) -> Self {
+        Self {
+            values,
+            ..Self::default()
+        }
+    }
+}
+
+/// The response when updating a range of values.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct UpdateValuesResponse {
+    /// The spreadsheet the updates were applied to.
+    #[serde(rename(serialize = "spreadsheetId", deserialize = "spreadsheetId"), default)]
+    pub spreadsheet_id: String,
+    /// The range (in A1 notation) that updates were applied to.
+    #[serde(rename(serialize = "updatedRange", deserialize = "updatedRange"), default)]
+    pub updated_range: String,
+    /// The number of rows where at least one cell in the row was updated.
+    #[serde(rename(serialize = "updatedRows", deserialize = "updatedRows"), default)]
+    pub updated_rows: i32,
+    /// The number of columns where at least one cell in the column was updated.
+    #[serde(rename(serialize = "updatedColumns", deserialize = "updatedColumns"), default)]
+    pub updated_columns: i32,
+    /// The number of cells updated.
+    #[serde(rename(serialize = "updatedCells", deserialize = "updatedCells"), default)]
+    pub updated_cells: i32,
+    /// The values of the cells after updates were applied, if `includeValuesInResponse` was set.
+    #[serde(rename(serialize = "updatedData", deserialize = "updatedData"), skip_serializing_if = "Option::is_none")]
+    pub updated_data: Option<ValueRange>,
+}
+
+/// The response when appending a range of values.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct AppendValuesResponse {
+    /// The spreadsheet the updates were applied to.
+    #[serde(rename(serialize = "spreadsheetId", deserialize = "spreadsheetId"), default)]
+    pub spreadsheet_id: String,
+    /// The range (in A1 notation) of the table that values are being appended to, before the values
+    /// were appended. Unset if no table was found.
+    #[serde(rename(serialize = "tableRange", deserialize = "tableRange"), skip_serializing_if = "Option::is_none")]
+    pub table_range: Option<String>,
+    /// Information about the updates that were applied.
+    #[serde(default)]
+    pub updates: UpdateValuesResponse,
+}
+
+/// The response when clearing a range of values.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ClearValuesResponse {
+    /// The spreadsheet the updates were applied to.
+    #[serde(rename(serialize = "spreadsheetId", deserialize = "spreadsheetId"), default)]
+    pub spreadsheet_id: String,
+    /// The range (in A1 notation) that was cleared, bounded to the sheet's limits.
+    #[serde(rename(serialize = "clearedRange", deserialize = "clearedRange"), default)]
+    pub cleared_range: String,
+}
+
+/// The response when reading several ranges of values.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct BatchGetValuesResponse {
+    /// The ID of the spreadsheet the data was retrieved from.
+    #[serde(rename(serialize = "spreadsheetId", deserialize = "spreadsheetId"), default)]
+    pub spreadsheet_id: String,
+    /// The requested values, in the order the ranges were requested.
+    #[serde(rename(serialize = "valueRanges", deserialize = "valueRanges"), default)]
+    pub value_ranges: Vec<ValueRange>,
+}
+
+/// The response when updating several ranges of values.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct BatchUpdateValuesResponse {
+    /// The spreadsheet the updates were applied to.
+    #[serde(rename(serialize = "spreadsheetId", deserialize = "spreadsheetId"), default)]
+    pub spreadsheet_id: String,
+    /// The total number of rows where at least one cell in the row was updated.
+    #[serde(rename(serialize = "totalUpdatedRows", deserialize = "totalUpdatedRows"), default)]
+    pub total_updated_rows: i32,
+    /// The total number of columns where at least one cell in the column was updated.
+    #[serde(rename(serialize = "totalUpdatedColumns", deserialize = "totalUpdatedColumns"), default)]
+    pub total_updated_columns: i32,
+    /// The total number of cells updated.
+    #[serde(rename(serialize = "totalUpdatedCells", deserialize = "totalUpdatedCells"), default)]
+    pub total_updated_cells: i32,
+    /// The total number of sheets where at least one cell in the sheet was updated.
+    #[serde(rename(serialize = "totalUpdatedSheets", deserialize = "totalUpdatedSheets"), default)]
+    pub total_updated_sheets: i32,
+    /// One response per updated range, in the order the ranges were requested.
+    #[serde(default)]
+    pub responses: Vec<UpdateValuesResponse>,
+}
+
+/// The response when clearing several ranges of values.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct BatchClearValuesResponse {
+    /// The spreadsheet the updates were applied to.
+    #[serde(rename(serialize = "spreadsheetId", deserialize = "spreadsheetId"), default)]
+    pub spreadsheet_id: String,
+    /// The ranges (in A1 notation) that were cleared, in the order they were requested, bounded to
+    /// the sheets' limits.
+    #[serde(rename(serialize = "clearedRanges", deserialize = "clearedRanges"), default)]
+    pub cleared_ranges: Vec<String>,
+}
+
+/// Selects developer metadata by any combination of its fields, e.g. all metadata with a key.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DeveloperMetadataLookup {
+    /// Limits the selected metadata to that associated with locations of this type.
+    #[serde(rename(serialize = "locationType", deserialize = "locationType"), skip_serializing_if = "Option::is_none")]
+    pub location_type: Option<LocationType>,
+    /// Limits the selected metadata to that associated with this location.
+    #[serde(rename(serialize = "metadataLocation", deserialize = "metadataLocation"), skip_serializing_if = "Option::is_none")]
+    pub metadata_location: Option<DeveloperMetadataLocation>,
+    /// How `metadata_location` is matched, `EXACT_LOCATION` if unset.
+    #[serde(rename(serialize = "locationMatchingStrategy", deserialize = "locationMatchingStrategy"), skip_serializing_if = "Option::is_none")]
+    pub location_matching_strategy: Option<LocationMatchingStrategy>,
+    /// Limits the selected metadata to the one with this ID.
+    #[serde(rename(serialize = "metadataId", deserialize = "metadataId"), skip_serializing_if = "Option::is_none")]
+    pub metadata_id: Option<i32>,
+    /// Limits the selected metadata to that with this key.
+    #[serde(rename(serialize = "metadataKey", deserialize = "metadataKey"), skip_serializing_if = "Option::is_none")]
+    pub metadata_key: Option<String>,
+    /// Limits the selected metadata to that with this value.
+    #[serde(rename(serialize = "metadataValue", deserialize = "metadataValue"), skip_serializing_if = "Option::is_none")]
+    pub metadata_value: Option<String>,
+    /// Limits the selected metadata to that with this visibility.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
+}
+
+impl DeveloperMetadataLookup {
+    /// All metadata with `key`, e.g. to select the rows tagged with it.
+    pub fn with_key<S: Into<String>>(key: S) -> Self {
+        Self {
+            metadata_key: Some(key.into()),
+            ..Self::default()
+        }
+    }
+}
+
+/// Selects the data of a spreadsheet to read or write.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum DataFilter {
+    /// The locations of the developer metadata matching the lookup.
+    #[serde(rename(serialize = "developerMetadataLookup", deserialize = "developerMetadataLookup"))]
+    DeveloperMetadataLookup(DeveloperMetadataLookup),
+    /// A range in A1 notation.
+    #[serde(rename(serialize = "a1Range", deserialize = "a1Range"))]
+    A1Range(String),
+    /// A range on a sheet.
+    #[serde(rename(serialize = "gridRange", deserialize = "gridRange"))]
+    GridRange(GridRange),
+}
+
+/// Values to write to the range matched by a data filter.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DataFilterValueRange {
+    /// The data filter selecting the location of the values. It must match a single range.
+    #[serde(rename(serialize = "dataFilter", deserialize = "dataFilter"))]
+    pub data_filter: DataFilter,
+    /// The major dimension of the values, `ROWS` if unset.
+    #[serde(rename(serialize = "majorDimension", deserialize = "majorDimension"), skip_serializing_if = "Option::is_none")]
+    pub major_dimension: Option<Dimension>,
+    /// The data, one list per row (or column).
+    #[serde(default)]
+    pub values: Vec<Vec<serde_json::Value>>,
+}
+
+/// Values read for the data filters matching a range.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MatchedValueRange {
+    /// The values matched by the data filters.
+    #[serde(rename(serialize = "valueRange", deserialize = "valueRange"), default)]
+    pub value_range: ValueRange,
+    /// The data filters of the request that matched the range.
+    #[serde(rename(serialize = "dataFilters", deserialize = "dataFilters"), default)]
+    pub data_filters: Vec<DataFilter>,
+}
+
+/// The response when reading ranges of values selected by data filters.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct BatchGetValuesByDataFilterResponse {
+    /// The ID of the spreadsheet the data was retrieved from.
+    #[serde(rename(serialize = "spreadsheetId", deserialize = "spreadsheetId"), default)]
+    pub spreadsheet_id: String,
+    /// The values of the ranges matched by the data filters.
+    #[serde(rename(serialize = "valueRanges", deserialize = "valueRanges"), default)]
+    pub value_ranges: Vec<MatchedValueRange>,
+}
+
+/// The response when updating a range of values selected by a data filter.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct UpdateValuesByDataFilterResponse {
+    /// The range (in A1 notation) that updates were applied to.
+    #[serde(rename(serialize = "updatedRange", deserialize = "updatedRange"), default)]
+    pub updated_range: String,
+    /// The number of rows where at least one cell in the row was updated.
+    #[serde(rename(serialize = "updatedRows", deserialize = "updatedRows"), default)]
+    pub updated_rows: i32,
+    /// The number of columns where at least one cell in the column was updated.
+    #[serde(rename(serialize = "updatedColumns", deserialize = "updatedColumns"), default)]
+    pub updated_columns: i32,
+    /// The number of cells updated.
+    #[serde(rename(serialize = "updatedCells", deserialize = "updatedCells"), default)]
+    pub updated_cells: i32,
+    /// The data filter that selected the updated range.
+    #[serde(rename(serialize = "dataFilter", deserialize = "dataFilter"), skip_serializing_if = "Option::is_none")]
+    pub data_filter: Option<DataFilter>,
+    /// The values of the cells after updates were applied, if `includeValuesInResponse` was set.
+    #[serde(rename(serialize = "updatedData", deserialize = "updatedData"), skip_serializing_if = "Option::is_none")]
+    pub updated_data: Option<ValueRange>,
+}
+
+/// The response when updating ranges of values selected by data filters.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct BatchUpdateValuesByDataFilterResponse {
+    /// The spreadsheet the updates were applied to.
+    #[serde(rename(serialize = "spreadsheetId", deserialize = "spreadsheetId"), default)]
+    pub spreadsheet_id: String,
+    /// The total number of rows where at least one cell in the row was updated.
+    #[serde(rename(serialize = "totalUpdatedRows", deserialize = "totalUpdatedRows"), default)]
+    pub total_updated_rows: i32,
+    /// The total number of columns where at least one cell in the column was updated.
+    #[serde(rename(serialize = "totalUpdatedColumns", deserialize = "totalUpdatedColumns"), default)]
+    pub total_updated_columns: i32,
+    /// The total number of cells updated.
+    #[serde(rename(serialize = "totalUpdatedCells", deserialize = "totalUpdatedCells"), default)]
+    pub total_updated_cells: i32,
+    /// The total number of sheets where at least one cell in the sheet was updated.
+    #[serde(rename(serialize = "totalUpdatedSheets", deserialize = "totalUpdatedSheets"), default)]
+    pub total_updated_sheets: i32,
+    /// One response per updated range, in the order of the request's data.
+    #[serde(default)]
+    pub responses: Vec<UpdateValuesByDataFilterResponse>,
+}
+
+/// The response when clearing ranges of values selected by data filters.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct BatchClearValuesByDataFilterResponse {
+    /// The spreadsheet the updates were applied to.
+    #[serde(rename(serialize = "spreadsheetId", deserialize = "spreadsheetId"), default)]
+    pub spreadsheet_id: String,
+    /// The ranges (in A1 notation) that were cleared, bounded to the sheets' limits.
+    #[serde(rename(serialize = "clearedRanges", deserialize = "clearedRanges"), default)]
+    pub cleared_ranges: Vec<String>,
 }
@@ -0,0 +1,831 @@
+//! The requests of `SheetApi::batch_update`, changing the structure of a spreadsheet, and their replies.
+
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize};
+#[cfg(test)]
+use serde_json::json;
+
+use super::enums::Dimension;
+use super::models::{
+    CellData, DimensionProperties, DimensionRange, GridCoordinate, GridRange, RowData, SheetProperties, Spreadsheet,
+    SpreadsheetProperties,
+};
+
+/// A single change to a spreadsheet, sent as an object with one field named after the kind of change.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum Request {
+    #[serde(rename(serialize = "updateSpreadsheetProperties", deserialize = "updateSpreadsheetProperties"))]
+    UpdateSpreadsheetProperties(Box<UpdateSpreadsheetPropertiesRequest>),
+    #[serde(rename(serialize = "addSheet", deserialize = "addSheet"))]
+    AddSheet(AddSheetRequest),
+    #[serde(rename(serialize = "deleteSheet", deserialize = "deleteSheet"))]
+    DeleteSheet(DeleteSheetRequest),
+    #[serde(rename(serialize = "duplicateSheet", deserialize = "duplicateSheet"))]
+    DuplicateSheet(DuplicateSheetRequest),
+    #[serde(rename(serialize = "updateSheetProperties", deserialize = "updateSheetProperties"))]
+    UpdateSheetProperties(UpdateSheetPropertiesRequest),
+    #[serde(rename(serialize = "insertDimension", deserialize = "insertDimension"))]
+    InsertDimension(InsertDimensionRequest),
+    #[serde(rename(serialize = "deleteDimension", deserialize = "deleteDimension"))]
+    DeleteDimension(DeleteDimensionRequest),
+    #[serde(rename(serialize = "moveDimension", deserialize = "moveDimension"))]
+    MoveDimension(MoveDimensionRequest),
+    #[serde(rename(serialize = "appendDimension", deserialize = "appendDimension"))]
+    AppendDimension(AppendDimensionRequest),
+    #[serde(rename(serialize = "autoResizeDimensions", deserialize = "autoResizeDimensions"))]
+    AutoResizeDimensions(AutoResizeDimensionsRequest),
+    #[serde(rename(serialize = "updateDimensionProperties", deserialize = "updateDimensionProperties"))]
+    UpdateDimensionProperties(UpdateDimensionPropertiesRequest),
+    #[serde(rename(serialize = "updateCells", deserialize = "updateCells"))]
+    UpdateCells(UpdateCellsRequest),
+    #[serde(rename(serialize = "repeatCell", deserialize = "repeatCell"))]
+    RepeatCell(Box<RepeatCellRequest>),
+    #[serde(rename(serialize = "appendCells", deserialize = "appendCells"))]
+    AppendCells(AppendCellsRequest),
+}
+
+/// The reply to a `Request`, in the same order.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[non_exhaustive]
+pub enum Response {
+    #[serde(rename(serialize = "addSheet"))]
+    AddSheet(AddSheetResponse),
+    #[serde(rename(serialize = "duplicateSheet"))]
+    DuplicateSheet(DuplicateSheetResponse),
+    /// The empty reply of requests without one, e.g. `UpdateSpreadsheetProperties`, as well as the
+    /// replies not modelled yet. A modelled reply that doesn't decode is an error rather than `Other`.
+    #[serde(untagged)]
+    Other(serde_json::Map<String, serde_json::Value>),
+}
+
+impl<'de> Deserialize<'de> for Response {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let reply = serde_json::Map::<String, serde_json::Value>::deserialize(deserializer)?;
+
+        let (kind, body) = match reply.iter().next() {
+            Some(field) if reply.len() == 1 => field,
+            _ => return Ok(Self::Other(reply)),
+        };
+        let decoded = match kind.as_str() {
+            "addSheet" => AddSheetResponse::deserialize(body).map(Self::AddSheet),
+            "duplicateSheet" => DuplicateSheetResponse::deserialize(body).map(Self::DuplicateSheet),
+            _ => return Ok(Self::Other(reply)),
+        };
+
+        decoded.map_err(|e| D::Error::custom(format!("{}: {}", kind, e)))
+    }
+}
+
+impl Response {
+    /// The properties of the sheet added by `AddSheet` or `DuplicateSheet`, e.g. its ID for follow-up requests.
+    pub fn sheet_properties(&self) -> Option<&SheetProperties> {
+        match self {
+            Self::AddSheet(reply) => Some(&reply.properties),
+            Self::DuplicateSheet(reply) => Some(&reply.properties),
+            Self::Other(_) => None,
+        }
+    }
+}
+
+/// Updates the properties of the spreadsheet.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct UpdateSpreadsheetPropertiesRequest {
+    /// The properties to update.
+    pub properties: SpreadsheetProperties,
+    /// The fields of `properties` to update, comma-separated, e.g. `title,timeZone`, or `*` for all of them.
+    /// Fields in the mask but unset in `properties` are reset to their default.
+    pub fields: String,
+}
+
+impl UpdateSpreadsheetPropertiesRequest {
+    pub fn new<S: Into<String>>(properties: SpreadsheetProperties, fields: S) -> Self {
+        Self {
+            properties,
+            fields: fields.into(),
+        }
+    }
+}
+
+impl From<UpdateSpreadsheetPropertiesRequest> for Request {
+    fn from(request: UpdateSpreadsheetPropertiesRequest) -> Self {
+        Self::UpdateSpreadsheetProperties(Box::new(request))
+    }
+}
+
+/// Adds a sheet, at the end of the spreadsheet unless `properties.index` is set.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct AddSheetRequest {
+    /// The properties of the new sheet, all of them optional. The ID, if set, must not be used yet.
+    pub properties: SheetProperties,
+}
+
+impl AddSheetRequest {
+    pub fn new(properties: SheetProperties) -> Self {
+        Self { properties }
+    }
+}
+
+impl From<AddSheetRequest> for Request {
+    fn from(request: AddSheetRequest) -> Self {
+        Self::AddSheet(request)
+    }
+}
+
+/// Deletes a sheet.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DeleteSheetRequest {
+    /// The ID of the sheet to delete.
+    #[serde(rename(serialize = "sheetId", deserialize = "sheetId"))]
+    pub sheet_id: i32,
+}
+
+impl DeleteSheetRequest {
+    pub fn new(sheet_id: i32) -> Self {
+        Self { sheet_id }
+    }
+}
+
+impl From<DeleteSheetRequest> for Request {
+    fn from(request: DeleteSheetRequest) -> Self {
+        Self::DeleteSheet(request)
+    }
+}
+
+/// Duplicates the contents of a sheet.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DuplicateSheetRequest {
+    /// The sheet to duplicate.
+    #[serde(rename(serialize = "sourceSheetId", deserialize = "sourceSheetId"))]
+    pub source_sheet_id: i32,
+    /// The zero-based index where the new sheet is inserted, after the others if unset.
+    #[serde(rename(serialize = "insertSheetIndex", deserialize = "insertSheetIndex"), skip_serializing_if = "Option::is_none")]
+    pub insert_sheet_index: Option<i32>,
+    /// The ID of the new sheet, chosen by the server if unset. It must not be used yet.
+    #[serde(rename(serialize = "newSheetId", deserialize = "newSheetId"), skip_serializing_if = "Option::is_none")]
+    pub new_sheet_id: Option<i32>,
+    /// The name of the new sheet, chosen by the server if unset.
+    #[serde(rename(serialize = "newSheetName", deserialize = "newSheetName"), skip_serializing_if = "Option::is_none")]
+    pub new_sheet_name: Option<String>,
+}
+
+impl DuplicateSheetRequest {
+    /// A copy of `source_sheet_id`, named and placed by the server.
+    pub fn new(source_sheet_id: i32) -> Self {
+        Self {
+            source_sheet_id,
+            insert_sheet_index: None,
+            new_sheet_id: None,
+            new_sheet_name: None,
+        }
+    }
+
+    pub fn with_insert_sheet_index(mut self, index: i32) -> Self {
+        self.insert_sheet_index = Some(index);
+        self
+    }
+
+    pub fn with_new_sheet_id(mut self, sheet_id: i32) -> Self {
+        self.new_sheet_id = Some(sheet_id);
+        self
+    }
+
+    pub fn with_new_sheet_name<S: Into<String>>(mut self, name: S) -> Self {
+        self.new_sheet_name = Some(name.into());
+        self
+    }
+}
+
+impl From<DuplicateSheetRequest> for Request {
+    fn from(request: DuplicateSheetRequest) -> Self {
+        Self::DuplicateSheet(request)
+    }
+}
+
+/// Updates the properties of the sheet `properties.sheet_id`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct UpdateSheetPropertiesRequest {
+    /// The properties to update, along with the ID of the sheet.
+    pub properties: SheetProperties,
+    /// The fields of `properties` to update, comma-separated, e.g. `title,gridProperties.frozenRowCount`,
+    /// or `*` for all of them. Fields in the mask but unset in `properties` are reset to their default.
+    pub fields: String,
+}
+
+impl UpdateSheetPropertiesRequest {
+    pub fn new<S: Into<String>>(properties: SheetProperties, fields: S) -> Self {
+        Self {
+            properties,
+            fields: fields.into(),
+        }
+    }
+}
+
+impl From<UpdateSheetPropertiesRequest> for Request {
+    fn from(request: UpdateSheetPropertiesRequest) -> Self {
+        Self::UpdateSheetProperties(request)
+    }
+}
+
+/// Inserts rows or columns, shifting the following ones.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct InsertDimensionRequest {
+    /// Where to insert the rows or columns. Its start and end indexes must be set.
+    pub range: DimensionRange,
+    /// Whether the new rows or columns inherit the properties of the ones before rather than after.
+    /// Must be false when inserting at index 0.
+    #[serde(rename(serialize = "inheritFromBefore", deserialize = "inheritFromBefore"), skip_serializing_if = "Option::is_none")]
+    pub inherit_from_before: Option<bool>,
+}
+
+impl InsertDimensionRequest {
+    pub fn new(range: DimensionRange) -> Self {
+        Self {
+            range,
+            inherit_from_before: None,
+        }
+    }
+
+    pub fn with_inherit_from_before(mut self, inherit: bool) -> Self {
+        self.inherit_from_before = Some(inherit);
+        self
+    }
+}
+
+impl From<InsertDimensionRequest> for Request {
+    fn from(request: InsertDimensionRequest) -> Self {
+        Self::InsertDimension(request)
+    }
+}
+
+/// Deletes rows or columns, shifting the following ones.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DeleteDimensionRequest {
+    /// The rows or columns to delete.
+    pub range: DimensionRange,
+}
+
+impl DeleteDimensionRequest {
+    pub fn new(range: DimensionRange) -> Self {
+        Self { range }
+    }
+}
+
+impl From<DeleteDimensionRequest> for Request {
+    fn from(request: DeleteDimensionRequest) -> Self {
+        Self::DeleteDimension(request)
+    }
+}
+
+/// Moves rows or columns within a sheet.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MoveDimensionRequest {
+    /// The rows or columns to move.
+    pub source: DimensionRange,
+    /// The zero-based index where the rows or columns move to, as indexed before they are removed from
+    /// their current position.
+    #[serde(rename(serialize = "destinationIndex", deserialize = "destinationIndex"))]
+    pub destination_index: i32,
+}
+
+impl MoveDimensionRequest {
+    pub fn new(source: DimensionRange, destination_index: i32) -> Self {
+        Self {
+            source,
+            destination_index,
+        }
+    }
+}
+
+impl From<MoveDimensionRequest> for Request {
+    fn from(request: MoveDimensionRequest) -> Self {
+        Self::MoveDimension(request)
+    }
+}
+
+/// Adds rows or columns at the end of a sheet.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AppendDimensionRequest {
+    /// The sheet to add rows or columns to.
+    #[serde(rename(serialize = "sheetId", deserialize = "sheetId"))]
+    pub sheet_id: i32,
+    /// Whether rows or columns are added.
+    pub dimension: Dimension,
+    /// The number of rows or columns to add.
+    pub length: i32,
+}
+
+impl AppendDimensionRequest {
+    pub fn new(sheet_id: i32, dimension: Dimension, length: i32) -> Self {
+        Self {
+            sheet_id,
+            dimension,
+            length,
+        }
+    }
+}
+
+impl From<AppendDimensionRequest> for Request {
+    fn from(request: AppendDimensionRequest) -> Self {
+        Self::AppendDimension(request)
+    }
+}
+
+/// Fits the height of rows or the width of columns to their contents.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AutoResizeDimensionsRequest {
+    /// The rows or columns to resize.
+    pub dimensions: DimensionRange,
+}
+
+impl AutoResizeDimensionsRequest {
+    pub fn new(dimensions: DimensionRange) -> Self {
+        Self { dimensions }
+    }
+}
+
+impl From<AutoResizeDimensionsRequest> for Request {
+    fn from(request: AutoResizeDimensionsRequest) -> Self {
+        Self::AutoResizeDimensions(request)
+    }
+}
+
+/// Updates the properties of rows or columns, e.g. to hide them or set their size.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct UpdateDimensionPropertiesRequest {
+    /// The rows or columns to update.
+    pub range: DimensionRange,
+    /// The properties to update.
+    pub properties: DimensionProperties,
+    /// The fields of `properties` to update, comma-separated, e.g. `hiddenByUser,pixelSize`, or `*` for all of them.
+    /// Fields in the mask but unset in `properties` are reset to their default.
+    pub fields: String,
+}
+
+impl UpdateDimensionPropertiesRequest {
+    pub fn new<S: Into<String>>(range: DimensionRange, properties: DimensionProperties, fields: S) -> Self {
+        Self {
+            range,
+            properties,
+            fields: fields.into(),
+        }
+    }
+}
+
+impl From<UpdateDimensionPropertiesRequest> for Request {
+    fn from(request: UpdateDimensionPropertiesRequest) -> Self {
+        Self::UpdateDimensionProperties(request)
+    }
+}
+
+/// Where `UpdateCells` writes its rows, sent as a field named after the kind of area.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum CellArea {
+    /// From this cell on, as far as the rows go.
+    #[serde(rename(serialize = "start", deserialize = "start"))]
+    Start(GridCoordinate),
+    /// Within this range. The fields in the mask are cleared in the cells of the range the rows don't cover.
+    #[serde(rename(serialize = "range", deserialize = "range"))]
+    Range(GridRange),
+}
+
+impl From<GridCoordinate> for CellArea {
+    fn from(start: GridCoordinate) -> Self {
+        Self::Start(start)
+    }
+}
+
+impl From<GridRange> for CellArea {
+    fn from(range: GridRange) -> Self {
+        Self::Range(range)
+    }
+}
+
+/// Updates the cells of an area with new data.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct UpdateCellsRequest {
+    /// Where to write the rows.
+    #[serde(flatten)]
+    pub area: CellArea,
+    /// The data to write, one entry per row starting with the first row of the area.
+    #[serde(default)]
+    pub rows: Vec<RowData>,
+    /// The fields of `CellData` to update, comma-separated, e.g. `userEnteredValue,note`, or `*` for all of them.
+    /// Fields in the mask but unset in the rows are cleared.
+    pub fields: String,
+}
+
+impl UpdateCellsRequest {
+    /// Writes `rows` starting at a `GridCoordinate`, or within a `GridRange`.
+    pub fn new<A: Into<CellArea>, S: Into<String>>(area: A, rows: Vec<RowData>, fields: S) -> Self {
+        Self {
+            area: area.into(),
+            rows,
+            fields: fields.into(),
+        }
+    }
+}
+
+impl From<UpdateCellsRequest> for Request {
+    fn from(request: UpdateCellsRequest) -> Self {
+        Self::UpdateCells(request)
+    }
+}
+
+/// Sets every cell of a range to the same data.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RepeatCellRequest {
+    /// The range to repeat the cell in.
+    pub range: GridRange,
+    /// The data to write.
+    pub cell: CellData,
+    /// The fields of `cell` to update, comma-separated, e.g. `userEnteredFormat`, or `*` for all of them.
+    /// Fields in the mask but unset in `cell` are cleared.
+    pub fields: String,
+}
+
+impl RepeatCellRequest {
+    pub fn new<S: Into<String>>(range: GridRange, cell: CellData, fields: S) -> Self {
+        Self {
+            range,
+            cell,
+            fields: fields.into(),
+        }
+    }
+}
+
+impl From<RepeatCellRequest> for Request {
+    fn from(request: RepeatCellRequest) -> Self {
+        Self::RepeatCell(Box::new(request))
+    }
+}
+
+/// Adds rows of data after the last row with data in a sheet, inserting rows if needed.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AppendCellsRequest {
+    /// The sheet to append the rows to.
+    #[serde(rename(serialize = "sheetId", deserialize = "sheetId"))]
+    pub sheet_id: i32,
+    /// The data to append.
+    #[serde(default)]
+    pub rows: Vec<RowData>,
+    /// The fields of `CellData` to write, comma-separated, e.g. `userEnteredValue`, or `*` for all of them.
+    pub fields: String,
+}
+
+impl AppendCellsRequest {
+    pub fn new<S: Into<String>>(sheet_id: i32, rows: Vec<RowData>, fields: S) -> Self {
+        Self {
+            sheet_id,
+            rows,
+            fields: fields.into(),
+        }
+    }
+}
+
+impl From<AppendCellsRequest> for Request {
+    fn from(request: AppendCellsRequest) -> Self {
+        Self::AppendCells(request)
+    }
+}
+
+/// The reply to `AddSheet`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AddSheetResponse {
+    /// The properties of the new sheet.
+    #[serde(default)]
+    pub properties: SheetProperties,
+}
+
+/// The reply to `DuplicateSheet`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DuplicateSheetResponse {
+    /// The properties of the new sheet.
+    #[serde(default)]
+    pub properties: SheetProperties,
+}
+
+/// The changes `SheetApi::batch_update` applies, all or none of them, and what it returns.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct BatchUpdateSpreadsheetRequest {
+    requests: Vec<Request>,
+    #[serde(rename(serialize = "includeSpreadsheetInResponse"), skip_serializing_if = "std::ops::Not::not")]
+    include_spreadsheet_in_response: bool,
+    #[serde(rename(serialize = "responseRanges"), skip_serializing_if = "Vec::is_empty")]
+    response_ranges: Vec<String>,
+    #[serde(rename(serialize = "responseIncludeGridData"), skip_serializing_if = "std::ops::Not::not")]
+    response_include_grid_data: bool,
+}
+
+impl BatchUpdateSpreadsheetRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Applies `request` too, after the ones already added.
+    pub fn with_request<R: Into<Request>>(mut self, request: R) -> Self {
+        self.push_request(request);
+        self
+    }
+
+    /// Applies `request` too, accumulating requests in a loop.
+    pub fn push_request<R: Into<Request>>(&mut self, request: R) {
+        self.requests.push(request.into());
+    }
+
+    /// Returns the updated spreadsheet in `updated_spreadsheet`.
+    pub fn with_spreadsheet_in_response(mut self, include: bool) -> Self {
+        self.include_spreadsheet_in_response = include;
+        self
+    }
+
+    /// Limits the cell data of the updated spreadsheet to `range`, in A1 notation.
+    /// Only meaningful along with `with_spreadsheet_in_response` and `with_response_grid_data`.
+    pub fn with_response_range<S: Into<String>>(mut self, range: S) -> Self {
+        self.response_ranges.push(range.into());
+        self
+    }
+
+    /// Returns the cells of the updated spreadsheet in `Sheet::data`.
+    /// Only meaningful along with `with_spreadsheet_in_response`.
+    pub fn with_response_grid_data(mut self, include: bool) -> Self {
+        self.response_include_grid_data = include;
+        self
+    }
+
+    /// The requests to apply, in the order of `BatchUpdateSpreadsheetResponse::replies`.
+    pub fn requests(&self) -> &[Request] {
+        &self.requests
+    }
+}
+
+/// The response of `SheetApi::batch_update`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct BatchUpdateSpreadsheetResponse {
+    /// The spreadsheet the updates were applied to.
+    #[serde(rename(serialize = "spreadsheetId", deserialize = "spreadsheetId"), default)]
+    pub spreadsheet_id: String,
+    /// One reply per request, in the order of the requests.
+    #[serde(default)]
+    pub replies: Vec<Response>,
+    /// The spreadsheet after updates were applied, if `includeSpreadsheetInResponse` was set.
+    #[serde(rename(serialize = "updatedSpreadsheet", deserialize = "updatedSpreadsheet"), skip_serializing_if = "Option::is_none")]
+    pub updated_spreadsheet: Option<Spreadsheet>,
+}
+
+//<editor-fold desc="Tests">
+
+#[test]
+fn test_sheet_requests() {
+    use super::models::GridProperties;
+
+    let mut template = SheetProperties::default();
+    template.title = Some("Template".to_string());
+    template.grid_properties = Some(GridProperties {
+        frozen_row_count: Some(1),
+        ..GridProperties::default()
+    });
+    let mut renamed = SheetProperties::default();
+    renamed.sheet_id = Some(7);
+    renamed.title = Some("2025-01".to_string());
+    renamed.hidden = Some(false);
+
+    let request = BatchUpdateSpreadsheetRequest::new()
+        .with_request(AddSheetRequest::new(template))
+        .with_request(DuplicateSheetRequest::new(3).with_insert_sheet_index(0).with_new_sheet_name("Acme"))
+        .with_request(UpdateSheetPropertiesRequest::new(renamed, "title,hidden"))
+        .with_request(DeleteSheetRequest::new(4));
+
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        json!({"requests": [
+            {"addSheet": {"properties": {"title": "Template", "gridProperties": {"frozenRowCount": 1}}}},
+            {"duplicateSheet": {"sourceSheetId": 3, "insertSheetIndex": 0, "newSheetName": "Acme"}},
+            {"updateSheetProperties": {"properties": {"sheetId": 7, "title": "2025-01", "hidden": false}, "fields": "title,hidden"}},
+            {"deleteSheet": {"sheetId": 4}}
+        ]})
+    );
+}
+
+#[test]
+fn test_dimension_requests() {
+    let mut hidden = DimensionProperties::default();
+    hidden.hidden_by_user = Some(true);
+
+    let request = BatchUpdateSpreadsheetRequest::new()
+        .with_request(InsertDimensionRequest::new(DimensionRange::rows(0, 0, 2)))
+        .with_request(DeleteDimensionRequest::new(DimensionRange::new(0, Dimension::Columns).with_start_index(10)))
+        .with_request(MoveDimensionRequest::new(DimensionRange::columns(0, 4, 5), 1))
+        .with_request(AppendDimensionRequest::new(0, Dimension::Rows, 500))
+        .with_request(AutoResizeDimensionsRequest::new(DimensionRange::new(0, Dimension::Columns)))
+        .with_request(UpdateDimensionPropertiesRequest::new(DimensionRange::columns(0, 2, 3), hidden, "hiddenByUser"));
+
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        json!({"requests": [
+            {"insertDimension": {"range": {"sheetId": 0, "dimension": "ROWS", "startIndex": 0, "endIndex": 2}}},
+            {"deleteDimension": {"range": {"sheetId": 0, "dimension": "COLUMNS", "startIndex": 10}}},
+            {"moveDimension": {"source": {"sheetId": 0, "dimension": "COLUMNS", "startIndex": 4, "endIndex": 5}, "destinationIndex": 1}},
+            {"appendDimension": {"sheetId": 0, "dimension": "ROWS", "length": 500}},
+            {"autoResizeDimensions": {"dimensions": {"sheetId": 0, "dimension": "COLUMNS"}}},
+            {"updateDimensionProperties": {
+                "range": {"sheetId": 0, "dimension": "COLUMNS", "startIndex": 2, "endIndex": 3},
+                "properties": {"hiddenByUser": true},
+                "fields": "hiddenByUser"
+            }}
+        ]})
+    );
+}
+
+#[test]
+fn test_cell_requests() {
+    use super::enums::ConditionType;
+    use super::models::{BooleanCondition, ConditionValue, DataValidationRule, ExtendedValue, TextFormatRun};
+
+    let header = RowData {
+        values: vec![
+            CellData::new(ExtendedValue::StringValue("Total".to_string())),
+            CellData::new(ExtendedValue::FormulaValue("=SUM(B2:B9)".to_string())),
+        ],
+    };
+    let mut note = CellData::new(ExtendedValue::NumberValue(1.5));
+    note.note = Some("estimated".to_string());
+    note.text_format_runs = Some(vec![TextFormatRun {
+        start_index: Some(0),
+        format: None,
+    }]);
+    let mut status = CellData::default();
+    status.data_validation = Some(DataValidationRule {
+        condition: BooleanCondition {
+            condition_type: ConditionType::OneOfList,
+            values: vec![ConditionValue {
+                user_entered_value: Some("open".to_string()),
+                ..ConditionValue::default()
+            }],
+        },
+        input_message: None,
+        strict: Some(true),
+        show_custom_ui: Some(true),
+    });
+    let column = GridRange {
+        sheet_id: 1,
+        start_row_index: Some(1),
+        start_column_index: Some(2),
+        end_column_index: Some(3),
+        ..GridRange::default()
+    };
+
+    let request = BatchUpdateSpreadsheetRequest::new()
+        .with_request(UpdateCellsRequest::new(GridCoordinate::new(1, 0, 0), vec![header], "userEnteredValue"))
+        .with_request(UpdateCellsRequest::new(column.clone(), vec![], "note"))
+        .with_request(RepeatCellRequest::new(column, status, "dataValidation"))
+        .with_request(AppendCellsRequest::new(1, vec![RowData { values: vec![note, CellData::default()] }], "*"));
+
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        json!({"requests": [
+            {"updateCells": {
+                "start": {"sheetId": 1, "rowIndex": 0, "columnIndex": 0},
+                "rows": [{"values": [{"userEnteredValue": {"stringValue": "Total"}}, {"userEnteredValue": {"formulaValue": "=SUM(B2:B9)"}}]}],
+                "fields": "userEnteredValue"
+            }},
+            {"updateCells": {
+                "range": {"sheetId": 1, "startRowIndex": 1, "startColumnIndex": 2, "endColumnIndex": 3},
+                "rows": [],
+                "fields": "note"
+            }},
+            {"repeatCell": {
+                "range": {"sheetId": 1, "startRowIndex": 1, "startColumnIndex": 2, "endColumnIndex": 3},
+                "cell": {"dataValidation": {
+                    "condition": {"type": "ONE_OF_LIST", "values": [{"userEnteredValue": "open"}]},
+                    "strict": true,
+                    "showCustomUi": true
+                }},
+                "fields": "dataValidation"
+            }},
+            {"appendCells": {
+                "sheetId": 1,
+                "rows": [{"values": [
+                    {"userEnteredValue": {"numberValue": 1.5}, "note": "estimated", "textFormatRuns": [{"startIndex": 0}]},
+                    {}
+                ]}],
+                "fields": "*"
+            }}
+        ]})
+    );
+}
+
+#[test]
+fn test_cell_format_request() {
+    use super::enums::{BorderStyle, HorizontalAlignment, NumberFormatType, ThemeColor};
+    use super::models::{Border, Borders, CellFormat, ColorStyle, Link, NumberFormat, TextFormat};
+
+    let mut header = CellData::default();
+    header.user_entered_format = Some(CellFormat {
+        number_format: Some(NumberFormat::new(NumberFormatType::Currency).with_pattern("$#,##0.00")),
+        borders: Some(Borders {
+            bottom: Some(Border::new(BorderStyle::SolidThick).with_color_style(ColorStyle {
+                theme_color: Some(ThemeColor::ACCENT1),
+                rgb_color: None,
+            })),
+            ..Borders::default()
+        }),
+        horizontal_alignment: Some(HorizontalAlignment::Center),
+        text_format: Some(TextFormat {
+            bold: Some(true),
+            link: Some(Link {
+                uri: Some("https://example.com".to_string()),
+            }),
+            ..TextFormat::default()
+        }),
+        ..CellFormat::default()
+    });
+    let range = GridRange {
+        sheet_id: 0,
+        end_row_index: Some(1),
+        ..GridRange::default()
+    };
+
+    let request = BatchUpdateSpreadsheetRequest::new().with_request(RepeatCellRequest::new(
+        range,
+        header.clone(),
+        "userEnteredFormat(numberFormat,borders,horizontalAlignment,textFormat)",
+    ));
+    let cell = json!({"userEnteredFormat": {
+        "numberFormat": {"type": "CURRENCY", "pattern": "$#,##0.00"},
+        "borders": {"bottom": {"style": "SOLID_THICK", "colorStyle": {"themeColor": "ACCENT1"}}},
+        "horizontalAlignment": "CENTER",
+        "textFormat": {"bold": true, "link": {"uri": "https://example.com"}}
+    }});
+
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        json!({"requests": [{"repeatCell": {
+            "range": {"sheetId": 0, "endRowIndex": 1},
+            "cell": cell,
+            "fields": "userEnteredFormat(numberFormat,borders,horizontalAlignment,textFormat)"
+        }}]})
+    );
+    assert_eq!(serde_json::from_value::<CellData>(cell).unwrap(), header);
+}
+
+#[test]
+fn test_response_decode() {
+    use std::convert::TryFrom;
+
+    use super::enums::{SheetType, ThemeColor};
+
+    let response: BatchUpdateSpreadsheetResponse = serde_json::from_value(json!({
+        "spreadsheetId": "id",
+        "replies": [{}, {"addChart": {"chart": {"chartId": 1}}}]
+    }))
+    .unwrap();
+
+    assert_eq!(response.replies[0], Response::Other(serde_json::Map::new()));
+    assert!(matches!(&response.replies[1], Response::Other(reply) if reply.contains_key("addChart")));
+    assert!(response.updated_spreadsheet.is_none());
+
+    let response: BatchUpdateSpreadsheetResponse = serde_json::from_value(json!({
+        "spreadsheetId": "id",
+        "replies": [
+            {"addSheet": {"properties": {"sheetId": 96, "title": "Sheet2", "index": 1, "sheetType": "GRID",
+                "gridProperties": {"rowCount": 1000, "columnCount": 26}}}},
+            {"duplicateSheet": {"properties": {"sheetId": 97, "title": "Acme", "index": 0, "sheetType": "GRID",
+                "gridProperties": {"rowCount": 1000, "columnCount": 26, "frozenRowCount": 1}}}},
+            {},
+            {}
+        ]
+    }))
+    .unwrap();
+
+    assert!(matches!(&response.replies[0], Response::AddSheet(reply) if reply.properties.sheet_id == Some(96)));
+    let duplicated = response.replies[1].sheet_properties().unwrap();
+    assert_eq!((duplicated.sheet_id, duplicated.title.as_deref()), (Some(97), Some("Acme")));
+    assert_eq!(duplicated.grid_properties.as_ref().unwrap().frozen_row_count, Some(1));
+    assert!(response.replies[2].sheet_properties().is_none());
+
+    // Values added to the API since are kept apart instead of failing the whole reply.
+    let reply: Response = serde_json::from_value(json!({"addSheet": {"properties": {"sheetId": 98, "sheetType": "NEW_KIND",
+        "tabColorStyle": {"themeColor": "ACCENT7"}}}}))
+    .unwrap();
+    let added = reply.sheet_properties().unwrap();
+    assert_eq!(added.sheet_id, Some(98));
+    assert_eq!(added.sheet_type, Some(SheetType::Unknown));
+    assert_eq!(added.tab_color_style.as_ref().unwrap().theme_color, Some(ThemeColor::Unknown));
+    // A known reply that doesn't decode is an error, not `Other`.
+    let error = serde_json::from_value::<Response>(json!({"addSheet": {"properties": {"sheetId": "98"}}})).unwrap_err();
+    assert!(error.to_string().starts_with("addSheet: "));
+
+    // Sending an unknown value back fails before anything is sent, rather than have the API reject the batch.
+    let transport = |_: &crate::transport::Request| -> crate::error::Result<crate::transport::Response> {
+        panic!("nothing should be sent")
+    };
+    let api = crate::client::Client::new(crate::client::Version::V4).with_transport(transport).sheet();
+    let request = BatchUpdateSpreadsheetRequest::new().with_request(UpdateSheetPropertiesRequest::new(added.clone(), "*"));
+    match api.batch_update("id", &request) {
+        Err(crate::error::Error::Encode(message)) => assert!(message.contains("Unknown"), "{}", message),
+        _ => panic!("expected an encode error"),
+    }
+    assert!(<&str>::try_from(ThemeColor::Unknown).is_err());
+}
+//</editor-fold>
@@ -0,0 +1,984 @@
+//! The `spreadsheets.values` resource: reading and writing cell values without their formatting.
+
+use std::convert::TryFrom;
+
+#[cfg(test)]
+use serde_json::json;
+
+use super::enums::{DateTimeRenderOption, Dimension, InsertDataOption, ValueInputOption, ValueRenderOption};
+use serde::Serialize;
+
+use super::models::{
+    AppendValuesResponse, BatchClearValuesByDataFilterResponse, BatchClearValuesResponse,
+    BatchGetValuesByDataFilterResponse, BatchGetValuesResponse, BatchUpdateValuesByDataFilterResponse,
+    BatchUpdateValuesResponse, ClearValuesResponse, DataFilter, DataFilterValueRange, UpdateValuesResponse, ValueRange,
+};
+use crate::auth::SPREADSHEETS_SCOPE;
+use crate::call::{decode_json, encode_segment, with_query, Call};
+use crate::client::Client;
+use crate::error::Result;
+use crate::transport::{Method, Request};
+
+/// The `spreadsheets.values` resource, from `SheetApi::values`.
+pub struct ValuesApi<'a> {
+    client: &'a Client,
+}
+
+impl<'a> ValuesApi<'a> {
+    pub(crate) fn new(client: &'a Client) -> Self {
+        Self { client }
+    }
+
+    /// Reads the values of `range`, in A1 notation.
+    pub fn get(&self, spreadsheet_id: &str, range: &str, request: &GetValuesRequest) -> Result<ValueRange> {
+        self.client.execute(get(spreadsheet_id, range, request))
+    }
+
+    /// Writes `values` to `range`, in A1 notation.
+    pub fn update(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+        values: &ValueRange,
+        request: &UpdateValuesRequest,
+    ) -> Result<UpdateValuesResponse> {
+        self.client.execute(update(spreadsheet_id, range, values, request))
+    }
+
+    /// Writes `values` after the last row of the table found in `range`, in A1 notation.
+    pub fn append(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+        values: &ValueRange,
+        request: &AppendValuesRequest,
+    ) -> Result<AppendValuesResponse> {
+        self.client.execute(append(spreadsheet_id, range, values, request))
+    }
+
+    /// Clears the values of `range`, in A1 notation, keeping formatting and data validation.
+    pub fn clear(&self, spreadsheet_id: &str, range: &str) -> Result<ClearValuesResponse> {
+        self.client.execute(clear(spreadsheet_id, range))
+    }
+
+    /// Reads the values of several ranges in one call, returned in the order of `request.ranges()`.
+    /// Too many ranges to fit in a url are read by data filter instead, with the same response.
+    pub fn batch_get(&self, spreadsheet_id: &str, request: &BatchGetValuesRequest) -> Result<BatchGetValuesResponse> {
+        self.client.execute(batch_get(spreadsheet_id, request))
+    }
+
+    /// Writes the values of several ranges in one call, responses coming in the order of `request.data()`.
+    pub fn batch_update(
+        &self,
+        spreadsheet_id: &str,
+        request: &BatchUpdateValuesRequest,
+    ) -> Result<BatchUpdateValuesResponse> {
+        self.client.execute(batch_update(spreadsheet_id, request))
+    }
+
+    /// Clears several ranges in one call, returned in the order of `request.ranges()`.
+    pub fn batch_clear(
+        &self,
+        spreadsheet_id: &str,
+        request: &BatchClearValuesRequest,
+    ) -> Result<BatchClearValuesResponse> {
+        self.client.execute(batch_clear(spreadsheet_id, request))
+    }
+
+    /// Reads the values of the ranges matched by the filters of `request`.
+    pub fn batch_get_by_data_filter(
+        &self,
+        spreadsheet_id: &str,
+        request: &BatchGetValuesByDataFilterRequest,
+    ) -> Result<BatchGetValuesByDataFilterResponse> {
+        self.client.execute(batch_get_by_data_filter(spreadsheet_id, request))
+    }
+
+    /// Writes values to the ranges matched by the filters of `request`, responses coming in the order of `request.data()`.
+    pub fn batch_update_by_data_filter(
+        &self,
+        spreadsheet_id: &str,
+        request: &BatchUpdateValuesByDataFilterRequest,
+    ) -> Result<BatchUpdateValuesByDataFilterResponse> {
+        self.client.execute(batch_update_by_data_filter(spreadsheet_id, request))
+    }
+
+    /// Clears the ranges matched by the filters of `request`.
+    pub fn batch_clear_by_data_filter(
+        &self,
+        spreadsheet_id: &str,
+        request: &BatchClearValuesByDataFilterRequest,
+    ) -> Result<BatchClearValuesByDataFilterResponse> {
+        self.client.execute(batch_clear_by_data_filter(spreadsheet_id, request))
+    }
+}
+
+/// How `ValuesApi::get` returns values: rows of formatted values by default.
+#[derive(Clone, Debug, Default)]
+pub struct GetValuesRequest {
+    major_dimension: Option<Dimension>,
+    value_render_option: Option<ValueRenderOption>,
+    date_time_render_option: Option<DateTimeRenderOption>,
+}
+
+impl GetValuesRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns one list per column with `Dimension::Columns`.
+    pub fn with_major_dimension(mut self, dimension: Dimension) -> Self {
+        self.major_dimension = Some(dimension);
+        self
+    }
+
+    pub fn with_value_render_option(mut self, option: ValueRenderOption) -> Self {
+        self.value_render_option = Some(option);
+        self
+    }
+
+    /// Ignored with `ValueRenderOption::FormattedValue`.
+    pub fn with_date_time_render_option(mut self, option: DateTimeRenderOption) -> Self {
+        self.date_time_render_option = Some(option);
+        self
+    }
+
+    /// Fails for `Dimension::Unknown`.
+    fn params(&self) -> Result<Vec<(&str, String)>> {
+        let mut params = vec![];
+
+        if let Some(dimension) = self.major_dimension {
+            params.push(("majorDimension", <&str>::try_from(dimension)?.to_string()));
+        }
+        if let Some(option) = self.value_render_option {
+            params.push(("valueRenderOption", <&str>::from(option).to_string()));
+        }
+        if let Some(option) = self.date_time_render_option {
+            params.push(("dateTimeRenderOption", <&str>::from(option).to_string()));
+        }
+        Ok(params)
+    }
+}
+
+/// How `ValuesApi::update` interprets values and what it returns.
+#[derive(Clone, Debug)]
+pub struct UpdateValuesRequest {
+    value_input_option: ValueInputOption,
+    include_values_in_response: bool,
+    response_value_render_option: Option<ValueRenderOption>,
+    response_date_time_render_option: Option<DateTimeRenderOption>,
+}
+
+impl UpdateValuesRequest {
+    /// Values are stored as-is with `ValueInputOption::Raw`, parsed like typed in the UI with `UserEntered`.
+    pub fn new(value_input_option: ValueInputOption) -> Self {
+        Self {
+            value_input_option,
+            include_values_in_response: false,
+            response_value_render_option: None,
+            response_date_time_render_option: None,
+        }
+    }
+
+    /// Returns the written values in `updated_data`.
+    pub fn with_values_in_response(mut self, include: bool) -> Self {
+        self.include_values_in_response = include;
+        self
+    }
+
+    /// How the values returned in the response are rendered.
+    pub fn with_response_value_render_option(mut self, option: ValueRenderOption) -> Self {
+        self.response_value_render_option = Some(option);
+        self
+    }
+
+    /// How dates and times returned in the response are rendered.
+    pub fn with_response_date_time_render_option(mut self, option: DateTimeRenderOption) -> Self {
+        self.response_date_time_render_option = Some(option);
+        self
+    }
+
+    fn params(&self) -> Vec<(&str, String)> {
+        let mut params = vec![("valueInputOption", <&str>::from(self.value_input_option).to_string())];
+
+        if self.include_values_in_response {
+            params.push(("includeValuesInResponse", "true".to_string()));
+        }
+        if let Some(option) = self.response_value_render_option {
+            params.push(("responseValueRenderOption", <&str>::from(option).to_string()));
+        }
+        if let Some(option) = self.response_date_time_render_option {
+            params.push(("responseDateTimeRenderOption", <&str>::from(option).to_string()));
+        }
+        params
+    }
+}
+
+/// How `ValuesApi::append` interprets and inserts values, and what it returns.
+#[derive(Clone, Debug)]
+pub struct AppendValuesRequest {
+    update: UpdateValuesRequest,
+    insert_data_option: Option<InsertDataOption>,
+}
+
+impl AppendValuesRequest {
+    /// Values are stored as-is with `ValueInputOption::Raw`, parsed like typed in the UI with `UserEntered`.
+    pub fn new(value_input_option: ValueInputOption) -> Self {
+        Self {
+            update: UpdateValuesRequest::new(value_input_option),
+            insert_data_option: None,
+        }
+    }
+
+    /// Inserts new rows for the values with `InsertDataOption::InsertRows`, instead of overwriting
+    /// the cells after the table.
+    pub fn with_insert_data_option(mut self, option: InsertDataOption) -> Self {
+        self.insert_data_option = Some(option);
+        self
+    }
+
+    /// Returns the written values in `updates.updated_data`.
+    pub fn with_values_in_response(mut self, include: bool) -> Self {
+        self.update = self.update.with_values_in_response(include);
+        self
+    }
+
+    /// How the values returned in the response are rendered.
+    pub fn with_response_value_render_option(mut self, option: ValueRenderOption) -> Self {
+        self.update = self.update.with_response_value_render_option(option);
+        self
+    }
+
+    /// How dates and times returned in the response are rendered.
+    pub fn with_response_date_time_render_option(mut self, option: DateTimeRenderOption) -> Self {
+        self.update = self.update.with_response_date_time_render_option(option);
+        self
+    }
+
+    fn params(&self) -> Vec<(&str, String)> {
+        let mut params = self.update.params();
+
+        if let Some(option) = self.insert_data_option {
+            params.push(("insertDataOption", <&str>::from(option).to_string()));
+        }
+        params
+    }
+}
+
+/// The ranges `ValuesApi::batch_get` reads, in A1 notation, and how it returns their values.
+#[derive(Clone, Debug, Default)]
+pub struct BatchGetValuesRequest {
+    ranges: Vec<String>,
+    options: GetValuesRequest,
+}
+
+impl BatchGetValuesRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads `range` too, e.g. `Sheet1!A1:C10`.
+    pub fn with_range<S: Into<String>>(mut self, range: S) -> Self {
+        self.push_range(range);
+        self
+    }
+
+    /// Reads `ranges` too.
+    pub fn with_ranges<I: IntoIterator<Item = S>, S: Into<String>>(mut self, ranges: I) -> Self {
+        self.ranges.extend(ranges.into_iter().map(Into::into));
+        self
+    }
+
+    /// Reads `range` too, accumulating ranges in a loop.
+    pub fn push_range<S: Into<String>>(&mut self, range: S) {
+        self.ranges.push(range.into());
+    }
+
+    /// Returns one list per column with `Dimension::Columns`.
+    pub fn with_major_dimension(mut self, dimension: Dimension) -> Self {
+        self.options = self.options.with_major_dimension(dimension);
+        self
+    }
+
+    pub fn with_value_render_option(mut self, option: ValueRenderOption) -> Self {
+        self.options = self.options.with_value_render_option(option);
+        self
+    }
+
+    /// Ignored with `ValueRenderOption::FormattedValue`.
+    pub fn with_date_time_render_option(mut self, option: DateTimeRenderOption) -> Self {
+        self.options = self.options.with_date_time_render_option(option);
+        self
+    }
+
+    /// The ranges to read, in the order of `BatchGetValuesResponse::value_ranges`.
+    pub fn ranges(&self) -> &[String] {
+        &self.ranges
+    }
+
+    fn params(&self) -> Result<Vec<(&str, String)>> {
+        let mut params: Vec<_> = self.ranges.iter().map(|range| ("ranges", range.clone())).collect();
+
+        params.extend(self.options.params()?);
+        Ok(params)
+    }
+}
+
+/// The ranges `ValuesApi::batch_update` writes, how it interprets their values and what it returns.
+#[derive(Clone, Debug, Serialize)]
+pub struct BatchUpdateValuesRequest {
+    #[serde(rename(serialize = "valueInputOption"))]
+    value_input_option: ValueInputOption,
+    data: Vec<ValueRange>,
+    #[serde(rename(serialize = "includeValuesInResponse"), skip_serializing_if = "std::ops::Not::not")]
+    include_values_in_response: bool,
+    #[serde(rename(serialize = "responseValueRenderOption"), skip_serializing_if = "Option::is_none")]
+    response_value_render_option: Option<ValueRenderOption>,
+    #[serde(rename(serialize = "responseDateTimeRenderOption"), skip_serializing_if = "Option::is_none")]
+    response_date_time_render_option: Option<DateTimeRenderOption>,
+}
+
+impl BatchUpdateValuesRequest {
+    /// Values are stored as-is with `ValueInputOption::Raw`, parsed like typed in the UI with `UserEntered`.
+    pub fn new(value_input_option: ValueInputOption) -> Self {
+        Self {
+            value_input_option,
+            data: vec![],
+            include_values_in_response: false,
+            response_value_render_option: None,
+            response_date_time_render_option: None,
+        }
+    }
+
+    /// Writes `values` to `range` too, in A1 notation.
+    pub fn with_values<S: Into<String>>(mut self, range: S, values: ValueRange) -> Self {
+        self.push_values(range, values);
+        self
+    }
+
+    /// Writes `values` to `range` too, accumulating ranges in a loop.
+    pub fn push_values<S: Into<String>>(&mut self, range: S, values: ValueRange) {
+        self.data.push(ValueRange {
+            range: Some(range.into()),
+            ..values
+        });
+    }
+
+    /// Returns the written values in each `updated_data`.
+    pub fn with_values_in_response(mut self, include: bool) -> Self {
+        self.include_values_in_response = include;
+        self
+    }
+
+    /// How the values returned in the response are rendered.
+    pub fn with_response_value_render_option(mut self, option: ValueRenderOption) -> Self {
+        self.response_value_render_option = Some(option);
+        self
+    }
+
+    /// How dates and times returned in the response are rendered.
+    pub fn with_response_date_time_render_option(mut self, option: DateTimeRenderOption) -> Self {
+        self.response_date_time_render_option = Some(option);
+        self
+    }
+
+    /// The values to write, in the order of `BatchUpdateValuesResponse::responses`.
+    pub fn data(&self) -> &[ValueRange] {
+        &self.data
+    }
+}
+
+/// The ranges `ValuesApi::batch_clear` clears, in A1 notation.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct BatchClearValuesRequest {
+    ranges: Vec<String>,
+}
+
+impl BatchClearValuesRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Clears `range` too.
+    pub fn with_range<S: Into<String>>(mut self, range: S) -> Self {
+        self.push_range(range);
+        self
+    }
+
+    /// Clears `ranges` too.
+    pub fn with_ranges<I: IntoIterator<Item = S>, S: Into<String>>(mut self, ranges: I) -> Self {
+        self.ranges.extend(ranges.into_iter().map(Into::into));
+        self
+    }
+
+    /// Clears `range` too, accumulating ranges in a loop.
+    pub fn push_range<S: Into<String>>(&mut self, range: S) {
+        self.ranges.push(range.into());
+    }
+
+    /// The ranges to clear, in the order of `BatchClearValuesResponse::cleared_ranges`.
+    pub fn ranges(&self) -> &[String] {
+        &self.ranges
+    }
+}
+
+/// The data filters `ValuesApi::batch_get_by_data_filter` reads, and how it returns their values.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct BatchGetValuesByDataFilterRequest {
+    #[serde(rename(serialize = "dataFilters"))]
+    data_filters: Vec<DataFilter>,
+    #[serde(rename(serialize = "majorDimension"), skip_serializing_if = "Option::is_none")]
+    major_dimension: Option<Dimension>,
+    #[serde(rename(serialize = "valueRenderOption"), skip_serializing_if = "Option::is_none")]
+    value_render_option: Option<ValueRenderOption>,
+    #[serde(rename(serialize = "dateTimeRenderOption"), skip_serializing_if = "Option::is_none")]
+    date_time_render_option: Option<DateTimeRenderOption>,
+}
+
+impl BatchGetValuesByDataFilterRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads the ranges matched by `filter` too.
+    pub fn with_data_filter(mut self, filter: DataFilter) -> Self {
+        self.push_data_filter(filter);
+        self
+    }
+
+    /// Reads the ranges matched by `filter` too, accumulating filters in a loop.
+    pub fn push_data_filter(&mut self, filter: DataFilter) {
+        self.data_filters.push(filter);
+    }
+
+    /// Returns one list per column with `Dimension::Columns`.
+    pub fn with_major_dimension(mut self, dimension: Dimension) -> Self {
+        self.major_dimension = Some(dimension);
+        self
+    }
+
+    pub fn with_value_render_option(mut self, option: ValueRenderOption) -> Self {
+        self.value_render_option = Some(option);
+        self
+    }
+
+    /// Ignored with `ValueRenderOption::FormattedValue`.
+    pub fn with_date_time_render_option(mut self, option: DateTimeRenderOption) -> Self {
+        self.date_time_render_option = Some(option);
+        self
+    }
+}
+
+/// The values `ValuesApi::batch_update_by_data_filter` writes, how it interprets them and what it returns.
+#[derive(Clone, Debug, Serialize)]
+pub struct BatchUpdateValuesByDataFilterRequest {
+    #[serde(rename(serialize = "valueInputOption"))]
+    value_input_option: ValueInputOption,
+    data: Vec<DataFilterValueRange>,
+    #[serde(rename(serialize = "includeValuesInResponse"), skip_serializing_if = "std::ops::Not::not")]
+    include_values_in_response: bool,
+    #[serde(rename(serialize = "responseValueRenderOption"), skip_serializing_if = "Option::is_none")]
+    response_value_render_option: Option<ValueRenderOption>,
+    #[serde(rename(serialize = "responseDateTimeRenderOption"), skip_serializing_if = "Option::is_none")]
+    response_date_time_render_option: Option<DateTimeRenderOption>,
+}
+
+impl BatchUpdateValuesByDataFilterRequest {
+    /// Values are stored as-is with `ValueInputOption::Raw`, parsed like typed in the UI with `UserEntered`.
+    pub fn new(value_input_option: ValueInputOption) -> Self {
+        Self {
+            value_input_option,
+            data: vec![],
+            include_values_in_response: false,
+            response_value_render_option: None,
+            response_date_time_render_option: None,
+        }
+    }
+
+    /// Writes `values` to the range matched by `filter` too, which must match a single range.
+    pub fn with_values(mut self, filter: DataFilter, values: ValueRange) -> Self {
+        self.push_values(filter, values);
+        self
+    }
+
+    /// Writes `values` to the range matched by `filter` too, accumulating ranges in a loop.
+    pub fn push_values(&mut self, filter: DataFilter, values: ValueRange) {
+        self.data.push(DataFilterValueRange {
+            data_filter: filter,
+            major_dimension: values.major_dimension,
+            values: values.values,
+        });
+    }
+
+    /// Returns the written values in each `updated_data`.
+    pub fn with_values_in_response(mut self, include: bool) -> Self {
+        self.include_values_in_response = include;
+        self
+    }
+
+    /// How the values returned in the response are rendered.
+    pub fn with_response_value_render_option(mut self, option: ValueRenderOption) -> Self {
+        self.response_value_render_option = Some(option);
+        self
+    }
+
+    /// How dates and times returned in the response are rendered.
+    pub fn with_response_date_time_render_option(mut self, option: DateTimeRenderOption) -> Self {
+        self.response_date_time_render_option = Some(option);
+        self
+    }
+
+    /// The values to write, in the order of `BatchUpdateValuesByDataFilterResponse::responses`.
+    pub fn data(&self) -> &[DataFilterValueRange] {
+        &self.data
+    }
+}
+
+/// The data filters `ValuesApi::batch_clear_by_data_filter` clears.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct BatchClearValuesByDataFilterRequest {
+    #[serde(rename(serialize = "dataFilters"))]
+    data_filters: Vec<DataFilter>,
+}
+
+impl BatchClearValuesByDataFilterRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Clears the ranges matched by `filter` too.
+    pub fn with_data_filter(mut self, filter: DataFilter) -> Self {
+        self.push_data_filter(filter);
+        self
+    }
+
+    /// Clears the ranges matched by `filter` too, accumulating filters in a loop.
+    pub fn push_data_filter(&mut self, filter: DataFilter) {
+        self.data_filters.push(filter);
+    }
+}
+
+fn path(spreadsheet_id: &str, range: &str) -> String {
+    format!("spreadsheets/{}/values/{}", encode_segment(spreadsheet_id), encode_segment(range))
+}
+
+/// `spreadsheets.values.get`, shared with the async API.
+pub(crate) fn get(spreadsheet_id: &str, range: &str, request: &GetValuesRequest) -> Call<ValueRange> {
+    let path = path(spreadsheet_id, range);
+    let params = match request.params() {
+        Ok(params) => params,
+        Err(e) => return Call::new(Request::new(Method::Get, path), &[SPREADSHEETS_SCOPE]).with_error(e),
+    };
+
+    Call::new(Request::new(Method::Get, with_query(path, &params)), &[SPREADSHEETS_SCOPE])
+}
+
+/// `spreadsheets.values.update`, shared with the async API.
+pub(crate) fn update(
+    spreadsheet_id: &str,
+    range: &str,
+    values: &ValueRange,
+    request: &UpdateValuesRequest,
+) -> Call<UpdateValuesResponse> {
+    let path = with_query(path(spreadsheet_id, range), &request.params());
+
+    Call::new(Request::new(Method::Put, path), &[SPREADSHEETS_SCOPE]).with_json(values)
+}
+
+/// `spreadsheets.values.append`, shared with the async API.
+pub(crate) fn append(
+    spreadsheet_id: &str,
+    range: &str,
+    values: &ValueRange,
+    request: &AppendValuesRequest,
+) -> Call<AppendValuesResponse> {
+    let path = with_query(format!("{}:append", path(spreadsheet_id, range)), &request.params());
+
+    Call::new(Request::new(Method::Post, path), &[SPREADSHEETS_SCOPE]).with_json(values)
+}
+
+/// `spreadsheets.values.clear`, shared with the async API.
+pub(crate) fn clear(spreadsheet_id: &str, range: &str) -> Call<ClearValuesResponse> {
+    let path = format!("{}:clear", path(spreadsheet_id, range));
+
+    Call::new(Request::new(Method::Post, path), &[SPREADSHEETS_SCOPE])
+        .with_json(&serde_json::Map::new())
+        .idempotent()
+}
+
+/// The longest path and query `batch_get` sends as a GET, comfortably under the url length Google accepts.
+const MAX_BATCH_GET_PATH: usize = 8000;
+
+/// `spreadsheets.values.batchGet`, shared with the async API.
+/// Past `MAX_BATCH_GET_PATH`, `spreadsheets.values.batchGetByDataFilter` with one A1 range filter per range,
+/// its values coming in the order of the filters.
+pub(crate) fn batch_get(spreadsheet_id: &str, request: &BatchGetValuesRequest) -> Call<BatchGetValuesResponse> {
+    let path = format!("spreadsheets/{}/values:batchGet", encode_segment(spreadsheet_id));
+    let params = match request.params() {
+        Ok(params) => params,
+        Err(e) => return Call::new(Request::new(Method::Get, path), &[SPREADSHEETS_SCOPE]).with_error(e),
+    };
+    let path = with_query(path, &params);
+
+    if path.len() <= MAX_BATCH_GET_PATH {
+        return Call::new(Request::new(Method::Get, path), &[SPREADSHEETS_SCOPE]);
+    }
+    let filters = BatchGetValuesByDataFilterRequest {
+        data_filters: request.ranges.iter().cloned().map(DataFilter::A1Range).collect(),
+        major_dimension: request.options.major_dimension,
+        value_render_option: request.options.value_render_option,
+        date_time_render_option: request.options.date_time_render_option,
+    };
+    let call = batch_get_by_data_filter(spreadsheet_id, &filters);
+
+    Call {
+        request: call.request,
+        scopes: call.scopes,
+        idempotent: call.idempotent,
+        operation: call.operation,
+        error: call.error,
+        decode: |response| {
+            let response: BatchGetValuesByDataFilterResponse = decode_json(response)?;
+
+            Ok(BatchGetValuesResponse {
+                spreadsheet_id: response.spreadsheet_id,
+                value_ranges: response.value_ranges.into_iter().map(|matched| matched.value_range).collect(),
+            })
+        },
+    }
+}
+
+/// `spreadsheets.values.batchUpdate`, shared with the async API.
+pub(crate) fn batch_update(spreadsheet_id: &str, request: &BatchUpdateValuesRequest) -> Call<BatchUpdateValuesResponse> {
+    let path = format!("spreadsheets/{}/values:batchUpdate", encode_segment(spreadsheet_id));
+
+    // Like `update`, writing the same values twice has the effect of writing them once.
+    Call::new(Request::new(Method::Post, path), &[SPREADSHEETS_SCOPE])
+        .with_json(request)
+        .idempotent()
+}
+
+/// `spreadsheets.values.batchClear`, shared with the async API.
+pub(crate) fn batch_clear(spreadsheet_id: &str, request: &BatchClearValuesRequest) -> Call<BatchClearValuesResponse> {
+    let path = format!("spreadsheets/{}/values:batchClear", encode_segment(spreadsheet_id));
+
+    Call::new(Request::new(Method::Post, path), &[SPREADSHEETS_SCOPE])
+        .with_json(request)
+        .idempotent()
+}
+
+/// `spreadsheets.values.batchGetByDataFilter`, shared with the async API.
+pub(crate) fn batch_get_by_data_filter(
+    spreadsheet_id: &str,
+    request: &BatchGetValuesByDataFilterRequest,
+) -> Call<BatchGetValuesByDataFilterResponse> {
+    let path = format!("spreadsheets/{}/values:batchGetByDataFilter", encode_segment(spreadsheet_id));
+
+    Call::new(Request::new(Method::Post, path), &[SPREADSHEETS_SCOPE])
+        .with_json(request)
+        .read_only()
+}
+
+/// `spreadsheets.values.batchUpdateByDataFilter`, shared with the async API.
+pub(crate) fn batch_update_by_data_filter(
+    spreadsheet_id: &str,
+    request: &BatchUpdateValuesByDataFilterRequest,
+) -> Call<BatchUpdateValuesByDataFilterResponse> {
+    let path = format!("spreadsheets/{}/values:batchUpdateByDataFilter", encode_segment(spreadsheet_id));
+
+    Call::new(Request::new(Method::Post, path), &[SPREADSHEETS_SCOPE])
+        .with_json(request)
+        .idempotent()
+}
+
+/// `spreadsheets.values.batchClearByDataFilter`, shared with the async API.
+pub(crate) fn batch_clear_by_data_filter(
+    spreadsheet_id: &str,
+    request: &BatchClearValuesByDataFilterRequest,
+) -> Call<BatchClearValuesByDataFilterResponse> {
+    let path = format!("spreadsheets/{}/values:batchClearByDataFilter", encode_segment(spreadsheet_id));
+
+    Call::new(Request::new(Method::Post, path), &[SPREADSHEETS_SCOPE])
+        .with_json(request)
+        .idempotent()
+}
+
+//<editor-fold desc="Tests">
+
+#[test]
+fn test_values() {
+    use crate::client::Version;
+    use crate::transport::Response;
+
+    let transport = |request: &Request| -> Result<Response> {
+        let body = request.body.as_ref().map(|body| serde_json::from_slice::<serde_json::Value>(body).unwrap());
+        let (method, url) = (request.method, request.url.trim_start_matches("https://sheets.googleapis.com/v4/"));
+
+        let response = match (method, url) {
+            (Method::Get, "spreadsheets/id/values/Sheet%201!A1%3AB2?majorDimension=COLUMNS&valueRenderOption=FORMULA") => {
+                json!({"range": "'Sheet 1'!A1:B2", "majorDimension": "COLUMNS", "values": [["a", "=B1"], [1.5]]})
+            }
+            (Method::Put, "spreadsheets/id/values/A1?valueInputOption=USER_ENTERED&includeValuesInResponse=true") => {
+                assert_eq!(body, Some(json!({"values": [["=1+1", true]]})));
+                json!({
+                    "spreadsheetId": "id", "updatedRange": "Sheet1!A1:B1", "updatedRows": 1, "updatedColumns": 2,
+                    "updatedCells": 2, "updatedData": {"range": "Sheet1!A1:B1", "majorDimension": "ROWS", "values": [["2", "TRUE"]]}
+                })
+            }
+            (Method::Post, "spreadsheets/id/values/A1%3AB:append?valueInputOption=RAW&insertDataOption=INSERT_ROWS") => {
+                assert_eq!(body, Some(json!({"range": "A1:B", "values": [["x", null]]})));
+                json!({
+                    "spreadsheetId": "id", "tableRange": "Sheet1!A1:B3",
+                    "updates": {"spreadsheetId": "id", "updatedRange": "Sheet1!A4", "updatedRows": 1, "updatedColumns": 1, "updatedCells": 1}
+                })
+            }
+            (Method::Post, "spreadsheets/id/values/A%3AZ:clear") => {
+                assert_eq!(body, Some(json!({})));
+                json!({"spreadsheetId": "id", "clearedRange": "Sheet1!A1:Z1000"})
+            }
+            _ => panic!("unexpected {:?} {}", method, url),
+        };
+        Ok(Response {
+            status: 200,
+            headers: vec![],
+            body: response.to_string().into_bytes(),
+        })
+    };
+    let api = Client::new(Version::V4).with_transport(transport).sheet();
+
+    let request = GetValuesRequest::new()
+        .with_major_dimension(Dimension::Columns)
+        .with_value_render_option(ValueRenderOption::Formula);
+    let read = api.values().get("id", "Sheet 1!A1:B2", &request).unwrap();
+    assert_eq!(read.major_dimension, Some(Dimension::Columns));
+    assert_eq!(read.values, vec![vec![json!("a"), json!("=B1")], vec![json!(1.5)]]);
+
+    let request = UpdateValuesRequest::new(ValueInputOption::UserEntered).with_values_in_response(true);
+    let updated = api.values().update("id", "A1", &ValueRange::new(vec![vec![json!("=1+1"), json!(true)]]), &request).unwrap();
+    assert_eq!((updated.updated_range.as_str(), updated.updated_cells), ("Sheet1!A1:B1", 2));
+    assert_eq!(updated.updated_data.unwrap().values, vec![vec![json!("2"), json!("TRUE")]]);
+
+    let values = ValueRange {
+        range: Some("A1:B".to_string()),
+        ..ValueRange::new(vec![vec![json!("x"), json!(null)]])
+    };
+    let request = AppendValuesRequest::new(ValueInputOption::Raw).with_insert_data_option(InsertDataOption::InsertRows);
+    let appended = api.values().append("id", "A1:B", &values, &request).unwrap();
+    assert_eq!(appended.table_range.as_deref(), Some("Sheet1!A1:B3"));
+    assert_eq!((appended.updates.updated_range.as_str(), appended.updates.updated_rows), ("Sheet1!A4", 1));
+    assert!(appended.updates.updated_data.is_none());
+
+    assert_eq!(api.values().clear("id", "A:Z").unwrap().cleared_range, "Sheet1!A1:Z1000");
+}
+
+#[test]
+fn test_batch_values() {
+    use crate::client::Version;
+    use crate::transport::Response;
+
+    let transport = |request: &Request| -> Result<Response> {
+        let body = request.body.as_ref().map(|body| serde_json::from_slice::<serde_json::Value>(body).unwrap());
+        let (method, url) = (request.method, request.url.trim_start_matches("https://sheets.googleapis.com/v4/"));
+
+        let response = match (method, url) {
+            (Method::Get, "spreadsheets/id/values:batchGet?ranges=B1&ranges=Sheet2%21A1%3AA2&valueRenderOption=UNFORMATTED_VALUE") => {
+                json!({"spreadsheetId": "id", "valueRanges": [
+                    {"range": "Sheet1!B1", "majorDimension": "ROWS", "values": [[2]]},
+                    {"range": "Sheet2!A1:A2", "majorDimension": "ROWS"}
+                ]})
+            }
+            (Method::Post, "spreadsheets/id/values:batchUpdate") => {
+                assert_eq!(
+                    body,
+                    Some(json!({"valueInputOption": "RAW", "data": [
+                        {"range": "A1", "values": [[0]]},
+                        {"range": "A2", "values": [[1]]},
+                        {"range": "A3", "majorDimension": "COLUMNS", "values": [[2, 3]]}
+                    ]}))
+                );
+                json!({
+                    "spreadsheetId": "id", "totalUpdatedRows": 4, "totalUpdatedColumns": 1, "totalUpdatedCells": 4, "totalUpdatedSheets": 1,
+                    "responses": [
+                        {"spreadsheetId": "id", "updatedRange": "Sheet1!A1", "updatedRows": 1, "updatedColumns": 1, "updatedCells": 1},
+                        {"spreadsheetId": "id", "updatedRange": "Sheet1!A2", "updatedRows": 1, "updatedColumns": 1, "updatedCells": 1},
+                        {"spreadsheetId": "id", "updatedRange": "Sheet1!A3:A4", "updatedRows": 2, "updatedColumns": 1, "updatedCells": 2}
+                    ]
+                })
+            }
+            (Method::Post, "spreadsheets/id/values:batchClear") => {
+                assert_eq!(body, Some(json!({"ranges": ["A:A", "Sheet2"]})));
+                json!({"spreadsheetId": "id", "clearedRanges": ["Sheet1!A1:A1000", "Sheet2!A1:Z1000"]})
+            }
+            _ => panic!("unexpected {:?} {}", method, url),
+        };
+        Ok(Response {
+            status: 200,
+            headers: vec![],
+            body: response.to_string().into_bytes(),
+        })
+    };
+    let api = Client::new(Version::V4).with_transport(transport).sheet();
+
+    let request = BatchGetValuesRequest::new()
+        .with_ranges(vec!["B1", "Sheet2!A1:A2"])
+        .with_value_render_option(ValueRenderOption::UnformattedValue);
+    let read = api.values().batch_get("id", &request).unwrap();
+    let read: Vec<_> = request.ranges().iter().zip(read.value_ranges).collect();
+    assert_eq!(read[0].0, "B1");
+    assert_eq!(read[0].1.values, vec![vec![json!(2)]]);
+    assert_eq!(read[1].0, "Sheet2!A1:A2");
+    assert!(read[1].1.values.is_empty());
+
+    let mut request = BatchUpdateValuesRequest::new(ValueInputOption::Raw);
+    for row in 0..2 {
+        request.push_values(format!("A{}", row + 1), ValueRange::new(vec![vec![json!(row)]]));
+    }
+    let columns = ValueRange {
+        major_dimension: Some(Dimension::Columns),
+        ..ValueRange::new(vec![vec![json!(2), json!(3)]])
+    };
+    let request = request.with_values("A3", columns);
+    let updated = api.values().batch_update("id", &request).unwrap();
+    assert_eq!((updated.total_updated_cells, updated.responses.len()), (4, 3));
+    assert_eq!(request.data()[2].range.as_deref(), Some("A3"));
+    assert_eq!(updated.responses[2].updated_range, "Sheet1!A3:A4");
+
+    let request = BatchClearValuesRequest::new().with_range("A:A").with_range("Sheet2");
+    let cleared = api.values().batch_clear("id", &request).unwrap();
+    assert_eq!(cleared.cleared_ranges, vec!["Sheet1!A1:A1000", "Sheet2!A1:Z1000"]);
+}
+
+#[test]
+fn test_paths_encode_id_and_range() {
+    let values = ValueRange::new(vec![]);
+
+    assert_eq!(
+        get("a/b#c", "'Q1 2024'!A1:B2", &GetValuesRequest::new()).request.url,
+        "spreadsheets/a%2Fb%23c/values/%27Q1%202024%27!A1%3AB2"
+    );
+    assert_eq!(
+        append("a/b#c", "Log?", &values, &AppendValuesRequest::new(ValueInputOption::Raw)).request.url,
+        "spreadsheets/a%2Fb%23c/values/Log%3F:append?valueInputOption=RAW"
+    );
+    assert_eq!(clear("a/b#c", "A:A").request.url, "spreadsheets/a%2Fb%23c/values/A%3AA:clear");
+    assert_eq!(batch_clear("a/b#c", &BatchClearValuesRequest::new()).request.url, "spreadsheets/a%2Fb%23c/values:batchClear");
+}
+
+#[test]
+fn test_batch_get_many_ranges() {
+    use crate::client::Version;
+    use crate::transport::Response;
+
+    let ranges: Vec<_> = (1..=1000).map(|row| format!("'Monthly report'!A{}:Z{}", row, row)).collect();
+    let transport = |request: &Request| -> Result<Response> {
+        let body: serde_json::Value = serde_json::from_slice(request.body.as_ref().unwrap()).unwrap();
+        assert_eq!(request.method, Method::Post);
+        assert_eq!(request.url, "https://sheets.googleapis.com/v4/spreadsheets/id/values:batchGetByDataFilter");
+        assert_eq!(body["valueRenderOption"], json!("FORMULA"));
+
+        let filters = body["dataFilters"].as_array().unwrap();
+        let value_ranges: Vec<_> = filters
+            .iter()
+            .map(|filter| json!({"valueRange": {"range": filter["a1Range"], "values": [[filter["a1Range"]]]}, "dataFilters": [filter]}))
+            .collect();
+        Ok(Response {
+            status: 200,
+            headers: vec![],
+            body: json!({"spreadsheetId": "id", "valueRanges": value_ranges}).to_string().into_bytes(),
+        })
+    };
+    let api = Client::new(Version::V4).with_transport(transport).sheet();
+
+    let request = BatchGetValuesRequest::new()
+        .with_ranges(ranges.clone())
+        .with_value_render_option(ValueRenderOption::Formula);
+    let read = api.values().batch_get("id", &request).unwrap();
+    assert_eq!(read.spreadsheet_id, "id");
+    assert_eq!(read.value_ranges.len(), ranges.len());
+    for (range, values) in ranges.iter().zip(read.value_ranges) {
+        assert_eq!(values.range.as_ref(), Some(range));
+        assert_eq!(values.values, vec![vec![json!(range)]]);
+    }
+}
+
+#[test]
+fn test_values_by_data_filter() {
+    use super::models::{DeveloperMetadataLookup, GridRange};
+    use crate::client::Version;
+    use crate::transport::Response;
+
+    let transport = |request: &Request| -> Result<Response> {
+        let body: serde_json::Value = serde_json::from_slice(request.body.as_ref().unwrap()).unwrap();
+        assert_eq!(request.method, Method::Post);
+
+        let response = match request.url.trim_start_matches("https://sheets.googleapis.com/v4/spreadsheets/id/values:") {
+            "batchGetByDataFilter" => {
+                assert_eq!(
+                    body,
+                    json!({"dataFilters": [{"developerMetadataLookup": {"metadataKey": "order"}}], "majorDimension": "ROWS"})
+                );
+                json!({"spreadsheetId": "id", "valueRanges": [{
+                    "valueRange": {"range": "Orders!A7:C7", "majorDimension": "ROWS", "values": [["42", "open", "3"]]},
+                    "dataFilters": [{"developerMetadataLookup": {"metadataKey": "order"}}]
+                }]})
+            }
+            "batchUpdateByDataFilter" => {
+                assert_eq!(
+                    body,
+                    json!({"valueInputOption": "USER_ENTERED", "data": [
+                        {"dataFilter": {"a1Range": "Orders!B7"}, "values": [["closed"]]},
+                        {"dataFilter": {"gridRange": {"sheetId": 3, "startRowIndex": 6, "endRowIndex": 7, "startColumnIndex": 2, "endColumnIndex": 3}}, "values": [[0]]}
+                    ]})
+                );
+                json!({"spreadsheetId": "id", "totalUpdatedRows": 1, "totalUpdatedColumns": 2, "totalUpdatedCells": 2, "totalUpdatedSheets": 1, "responses": [
+                    {"updatedRange": "Orders!B7", "updatedRows": 1, "updatedColumns": 1, "updatedCells": 1, "dataFilter": {"a1Range": "Orders!B7"}},
+                    {"updatedRange": "Orders!C7", "updatedRows": 1, "updatedColumns": 1, "updatedCells": 1,
+                     "dataFilter": {"gridRange": {"sheetId": 3, "startRowIndex": 6, "endRowIndex": 7, "startColumnIndex": 2, "endColumnIndex": 3}}}
+                ]})
+            }
+            "batchClearByDataFilter" => {
+                assert_eq!(body, json!({"dataFilters": [{"developerMetadataLookup": {"metadataKey": "order", "metadataValue": "42"}}]}));
+                json!({"spreadsheetId": "id", "clearedRanges": ["Orders!A7:C7"]})
+            }
+            url => panic!("unexpected {}", url),
+        };
+        Ok(Response {
+            status: 200,
+            headers: vec![],
+            body: response.to_string().into_bytes(),
+        })
+    };
+    let api = Client::new(Version::V4).with_transport(transport).sheet();
+    let order = DataFilter::DeveloperMetadataLookup(DeveloperMetadataLookup::with_key("order"));
+
+    let request = BatchGetValuesByDataFilterRequest::new()
+        .with_data_filter(order.clone())
+        .with_major_dimension(Dimension::Rows);
+    let read = api.values().batch_get_by_data_filter("id", &request).unwrap();
+    assert_eq!(read.value_ranges[0].value_range.values, vec![vec![json!("42"), json!("open"), json!("3")]]);
+    assert_eq!(read.value_ranges[0].data_filters, vec![order]);
+
+    let cell = GridRange {
+        sheet_id: 3,
+        start_row_index: Some(6),
+        end_row_index: Some(7),
+        start_column_index: Some(2),
+        end_column_index: Some(3),
+    };
+    let request = BatchUpdateValuesByDataFilterRequest::new(ValueInputOption::UserEntered)
+        .with_values(DataFilter::A1Range("Orders!B7".to_string()), ValueRange::new(vec![vec![json!("closed")]]))
+        .with_values(DataFilter::GridRange(cell.clone()), ValueRange::new(vec![vec![json!(0)]]));
+    let updated = api.values().batch_update_by_data_filter("id", &request).unwrap();
+    assert_eq!(updated.total_updated_cells, 2);
+    assert_eq!(updated.responses[1].data_filter, Some(DataFilter::GridRange(cell)));
+    assert_eq!(updated.responses[1].updated_range, "Orders!C7");
+
+    let lookup = DeveloperMetadataLookup {
+        metadata_value: Some("42".to_string()),
+        ..DeveloperMetadataLookup::with_key("order")
+    };
+    let request = BatchClearValuesByDataFilterRequest::new().with_data_filter(DataFilter::DeveloperMetadataLookup(lookup));
+    let cleared = api.values().batch_clear_by_data_filter("id", &request).unwrap();
+    assert_eq!(cleared.cleared_ranges, vec!["Orders!A7:C7"]);
+}
+//</editor-fold>
@@ -0,0 +1,147 @@
+use std::sync::Mutex;
+
+use ::curl::easy::{Easy, List};
+
+use super::{HttpOptions, Method, Request, Response, Transport};
+use crate::error::{Result, TransportError, TransportErrorKind};
+
+/// A `Transport` backed by libcurl.
+///
+/// Handles are pooled, so connections are kept alive and reused by later requests.
+pub struct CurlTransport {
+    options: HttpOptions,
+    handles: Mutex<Vec<Easy>>,
+}
+
+impl CurlTransport {
+    pub fn new() -> Self {
+        Self::with_options(HttpOptions::new())
+    }
+
+    pub fn with_options(options: HttpOptions) -> Self {
+        Self {
+            options,
+            handles: Mutex::new(vec![]),
+        }
+    }
+}
+
+impl Default for CurlTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Transport for CurlTransport {
+    fn send(&self, request: &Request) -> Result<Response> {
+        let pooled = self.handles.lock().unwrap_or_else(|e| e.into_inner()).pop();
+        let mut client = match pooled {
+            // Resetting the options keeps the handle's open connections.
+            Some(mut client) => {
+                client.reset();
+                client
+            }
+            None => Easy::new(),
+        };
+
+        let response = self.perform(&mut client, request);
+        self.handles.lock().unwrap_or_else(|e| e.into_inner()).push(client);
+
+        response
+    }
+}
+
+impl CurlTransport {
+    fn perform(&self, client: &mut Easy, request: &Request) -> Result<Response> {
+        client.url(&request.url)?;
+
+        match request.method {
+            Method::Get => client.get(true)?,
+            Method::Post => client.post(true)?,
+            method => client.custom_request(method.into())?,
+        }
+        if let Some(body) = &request.body {
+            client.post_fields_copy(body)?;
+        }
+        if let Some(timeout) = request.timeout {
+            client.timeout(timeout)?;
+        }
+        if let Some(proxy) = &self.options.proxy {
+            client.proxy(proxy)?;
+        }
+        if let Some(timeout) = self.options.connect_timeout {
+            client.connect_timeout(timeout)?;
+        }
+        if let Some(timeout) = self.options.read_timeout {
+            // Abort when less than a byte per second arrives for that long.
+            client.low_speed_limit(1)?;
+            client.low_speed_time(timeout)?;
+        }
+        if let Some(path) = &self.options.ca_bundle {
+            client.cainfo(path)?;
+        }
+
+        let mut list = List::new();
+        // Don't wait for `100 Continue` before sending larger bodies.
+        list.append("Expect:")?;
+        for (name, value) in &request.headers {
+            list.append(&format!("{}: {}", name, value))?;
+        }
+        client.http_headers(list)?;
+
+        let mut headers = Vec::new();
+        let mut body = Vec::new();
+
+        {
+            let mut transfer = client.transfer();
+            transfer.header_function(|line| {
+                let line = String::from_utf8_lossy(line);
+                match line.split_once(':') {
+                    Some((name, value)) => headers.push((name.trim().to_string(), value.trim().to_string())),
+                    // A new status line, e.g. after `100 Continue` or a redirect.
+                    None if line.starts_with("HTTP/") => headers.clear(),
+                    None => {}
+                }
+                true
+            })?;
+            transfer.write_function(|data| {
+                body.extend_from_slice(data);
+                Ok(data.len())
+            })?;
+            transfer.perform()?;
+        }
+
+        Ok(Response {
+            status: client.response_code()?,
+            headers,
+            body,
+        })
+    }
+}
+
+impl From<::curl::Error> for TransportError {
+    fn from(e: ::curl::Error) -> Self {
+        let kind = if e.is_operation_timedout() {
+            TransportErrorKind::Timeout
+        } else if e.is_couldnt_resolve_host()
+            || e.is_couldnt_resolve_proxy()
+            || e.is_couldnt_connect()
+            || e.is_send_error()
+            || e.is_recv_error()
+            || e.is_got_nothing()
+            || e.is_partial_file()
+        {
+            TransportErrorKind::Connect
+        } else {
+            TransportErrorKind::Other
+        };
+
+        Self::new(kind, e)
+    }
+}
+
+impl From<::curl::Error> for crate::error::Error {
+    fn from(e: ::curl::Error) -> Self {
+        Self::Transport(e.into())
+    }
+}
@@ -0,0 +1,283 @@
+//! The HTTP layer every API call and token request goes through.
+//!
+//! Two backends are provided: libcurl (the `curl` feature, on by default) and a pure-Rust
+//! reqwest/rustls one (the `rustls` feature). Anything implementing `Transport` can replace
+//! them, including a plain closure, which makes in-process mocks straightforward.
+//!
+//! With the `async` feature, `AsyncTransport` is the non-blocking counterpart used by the async client.
+
+#[cfg(feature = "curl")]
+mod curl;
+#[cfg(feature = "async")]
+mod nonblocking;
+#[cfg(feature = "rustls")]
+mod reqwest;
+
+use std::fmt::{Debug, Display, Formatter};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::error::Result;
+
+#[cfg(feature = "curl")]
+pub use self::curl::CurlTransport;
+#[cfg(feature = "rustls")]
+pub use self::reqwest::ReqwestTransport;
+#[cfg(all(feature = "rustls", feature = "async"))]
+pub use self::reqwest::AsyncReqwestTransport;
+#[cfg(feature = "async")]
+pub use self::nonblocking::{default_async_transport, AsyncTransport, BlockingTransport, BoxFuture};
+#[cfg(feature = "async")]
+pub(crate) use self::nonblocking::spawn_blocking;
+
+#[cfg(not(any(feature = "curl", feature = "rustls")))]
+compile_error!("enable the `curl` or the `rustls` feature to get an HTTP transport");
+
+/// An HTTP method.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Method {
+    Get,
+    Post,
+    Put,
+    Patch,
+    Delete,
+}
+
+impl From<Method> for &str {
+    fn from(method: Method) -> Self {
+        match method {
+            Method::Get => "GET",
+            Method::Post => "POST",
+            Method::Put => "PUT",
+            Method::Patch => "PATCH",
+            Method::Delete => "DELETE",
+        }
+    }
+}
+
+impl Display for Method {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", <&str>::from(*self))
+    }
+}
+
+/// An HTTP request.
+///
+/// Its `Debug` output leaves out the credentials it may carry:
+/// the `key` query parameter, the `Authorization` header and the body, e.g. a token request, only shown by size.
+#[derive(Clone)]
+pub struct Request {
+    pub method: Method,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<Vec<u8>>,
+    /// Overall deadline of the exchange, `None` for the transport's default.
+    pub timeout: Option<Duration>,
+}
+
+impl Request {
+    pub fn new<S: Into<String>>(method: Method, url: S) -> Self {
+        Self {
+            method,
+            url: url.into(),
+            headers: vec![],
+            body: None,
+            timeout: None,
+        }
+    }
+
+    pub fn header<N: Into<String>, V: Into<String>>(mut self, name: N, value: V) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    pub fn body<B: Into<Vec<u8>>>(mut self, content_type: &str, body: B) -> Self {
+        self.body = Some(body.into());
+        self.header("Content-Type", content_type)
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+}
+
+impl Debug for Request {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let headers: Vec<_> = self
+            .headers
+            .iter()
+            .map(|(name, value)| match name.eq_ignore_ascii_case("Authorization") {
+                true => (name.as_str(), "<redacted>"),
+                false => (name.as_str(), value.as_str()),
+            })
+            .collect();
+
+        f.debug_struct("Request")
+            .field("method", &self.method)
+            .field("url", &redact_api_key(&self.url))
+            .field("headers", &headers)
+            .field("body", &self.body.as_ref().map(|body| format!("{} bytes", body.len())))
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+/// `url` with the value of its `key` query parameter replaced.
+fn redact_api_key(url: &str) -> String {
+    let (path, query) = match url.split_once('?') {
+        Some(parts) => parts,
+        None => return url.to_string(),
+    };
+    let query: Vec<_> = query
+        .split('&')
+        .map(|pair| match pair.starts_with("key=") {
+            true => "key=<redacted>",
+            false => pair,
+        })
+        .collect();
+
+    format!("{}?{}", path, query.join("&"))
+}
+
+/// An HTTP response, with its whole body.
+#[derive(Clone, Debug)]
+pub struct Response {
+    pub status: u32,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl Response {
+    /// The first header named `name`, compared case-insensitively.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Connection settings of the built-in transports.
+#[derive(Clone, Debug, Default)]
+pub struct HttpOptions {
+    pub proxy: Option<String>,
+    pub connect_timeout: Option<Duration>,
+    pub read_timeout: Option<Duration>,
+    pub ca_bundle: Option<PathBuf>,
+}
+
+impl HttpOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sends every request through the proxy at `url`, e.g. `http://proxy.corp:3128`.
+    pub fn with_proxy<S: Into<String>>(mut self, url: S) -> Self {
+        self.proxy = Some(url.into());
+        self
+    }
+
+    /// Gives up on connecting after `timeout`.
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Gives up when no data is received for `timeout`.
+    pub fn with_read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout);
+        self
+    }
+
+    /// Trusts the certificates of the PEM file at `path`, e.g. those of a TLS-intercepting proxy.
+    pub fn with_ca_bundle<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.ca_bundle = Some(path.into());
+        self
+    }
+}
+
+/// Sends requests and returns responses. Non-2xx statuses are not errors at this level.
+pub trait Transport: Send + Sync {
+    fn send(&self, request: &Request) -> Result<Response>;
+}
+
+impl<F> Transport for F
+where
+    F: Fn(&Request) -> Result<Response> + Send + Sync,
+{
+    fn send(&self, request: &Request) -> Result<Response> {
+        self(request)
+    }
+}
+
+/// The transport of the enabled backend, libcurl if both are enabled.
+pub fn default_transport() -> Arc<dyn Transport> {
+    #[cfg(feature = "curl")]
+    return Arc::new(CurlTransport::new());
+
+    #[cfg(all(feature = "rustls", not(feature = "curl")))]
+    return Arc::new(ReqwestTransport::new());
+}
+
+/// The transport of the enabled backend configured with `options`, libcurl if both are enabled.
+pub fn http_transport(options: &HttpOptions) -> Result<Arc<dyn Transport>> {
+    #[cfg(feature = "curl")]
+    return Ok(Arc::new(CurlTransport::with_options(options.clone())));
+
+    #[cfg(all(feature = "rustls", not(feature = "curl")))]
+    return Ok(Arc::new(ReqwestTransport::with_options(options)?));
+}
+
+//<editor-fold desc="Tests">
+
+#[test]
+fn test_default_transport() {
+    let (url, server) = crate::test_server::serve(vec![(201, r#"{"ok":true}"#.to_string())]);
+
+    let request = Request::new(Method::Put, format!("{}/path?q=1", url))
+        .header("X-Test", "yes")
+        .body("application/json", r#"{"a":1}"#);
+    let response = default_transport().send(&request).unwrap();
+    let received = server.join().unwrap();
+
+    assert_eq!(response.status, 201);
+    assert_eq!(response.header("content-type"), Some("application/json"));
+    assert_eq!(response.body, br#"{"ok":true}"#);
+    assert_eq!(received[0].line, "PUT /path?q=1 HTTP/1.1");
+    assert_eq!(received[0].header("X-Test"), Some("yes"));
+    assert_eq!(received[0].body, r#"{"a":1}"#);
+}
+
+#[test]
+fn test_request_debug() {
+    let request = Request::new(Method::Post, "https://host/v4/spreadsheets/id?fields=a&key=AIzaSecret")
+        .header("Authorization", "Bearer ya29.secret")
+        .body("application/x-www-form-urlencoded", "refresh_token=1//secret");
+    let debug = format!("{:?}", request);
+
+    assert!(!debug.contains("secret"), "{}", debug);
+    assert!(debug.contains(r#"url: "https://host/v4/spreadsheets/id?fields=a&key=<redacted>""#));
+    assert!(debug.contains(r#"("Content-Type", "application/x-www-form-urlencoded")"#));
+    assert!(debug.contains(r#"body: Some("23 bytes")"#));
+}
+
+#[test]
+fn test_http_options() {
+    let (url, server) = crate::test_server::serve(vec![(200, String::new())]);
+
+    let options = HttpOptions::new()
+        .with_proxy(url)
+        .with_connect_timeout(Duration::from_secs(5))
+        .with_read_timeout(Duration::from_secs(5));
+    let response = http_transport(&options)
+        .unwrap()
+        .send(&Request::new(Method::Get, "http://sheets.invalid/v4/spreadsheets/id"))
+        .unwrap();
+    let received = server.join().unwrap();
+
+    assert_eq!(response.status, 200);
+    assert_eq!(received[0].line, "GET http://sheets.invalid/v4/spreadsheets/id HTTP/1.1");
+}
+//</editor-fold>
@@ -0,0 +1,79 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use super::{Request, Response, Transport};
+use crate::error::{Error, Result, TransportError, TransportErrorKind};
+
+/// A boxed future, as returned by `AsyncTransport::send`.
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Sends requests and returns responses without blocking the async runtime.
+/// Non-2xx statuses are not errors at this level.
+pub trait AsyncTransport: Send + Sync {
+    fn send<'a>(&'a self, request: &'a Request) -> BoxFuture<'a, Result<Response>>;
+}
+
+/// Runs a blocking `Transport` on tokio's blocking thread pool.
+pub struct BlockingTransport {
+    transport: Arc<dyn Transport>,
+}
+
+impl BlockingTransport {
+    pub fn new<T: Transport + 'static>(transport: T) -> Self {
+        Self::from_arc(Arc::new(transport))
+    }
+
+    pub fn from_arc(transport: Arc<dyn Transport>) -> Self {
+        Self { transport }
+    }
+}
+
+impl AsyncTransport for BlockingTransport {
+    fn send<'a>(&'a self, request: &'a Request) -> BoxFuture<'a, Result<Response>> {
+        let transport = self.transport.clone();
+        let request = request.clone();
+
+        Box::pin(spawn_blocking(move || transport.send(&request)))
+    }
+}
+
+/// Runs `f` on tokio's blocking thread pool.
+pub(crate) async fn spawn_blocking<T, F>(f: F) -> Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T> + Send + 'static,
+{
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|e| Error::from(TransportError::new(TransportErrorKind::Other, e)))?
+}
+
+/// The async reqwest transport with the `rustls` feature, the default blocking one on the blocking pool otherwise.
+pub fn default_async_transport() -> Arc<dyn AsyncTransport> {
+    #[cfg(feature = "rustls")]
+    return Arc::new(super::AsyncReqwestTransport::new());
+
+    #[cfg(not(feature = "rustls"))]
+    return Arc::new(BlockingTransport::from_arc(super::default_transport()));
+}
+
+//<editor-fold desc="Tests">
+
+#[tokio::test]
+async fn test_default_async_transport() {
+    let (url, server) = crate::test_server::serve(vec![(200, r#"{"ok":true}"#.to_string()); 2]);
+
+    let request = Request::new(super::Method::Post, format!("{}/path", url)).body("application/json", "{}");
+    let response = default_async_transport().send(&request).await.unwrap();
+    assert_eq!(response.status, 200);
+    assert_eq!(response.body, br#"{"ok":true}"#);
+
+    let response = BlockingTransport::from_arc(super::default_transport()).send(&request).await.unwrap();
+    assert_eq!(response.status, 200);
+
+    let received = server.join().unwrap();
+    assert_eq!(received[0].line, "POST /path HTTP/1.1");
+    assert_eq!(received[1].body, "{}");
+}
+//</editor-fold>
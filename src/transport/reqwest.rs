@@ -0,0 +1,201 @@
+use std::sync::Mutex;
+
+use ::reqwest::blocking::Client;
+use ::reqwest::{Certificate, ClientBuilder, Proxy};
+
+use super::{HttpOptions, Method, Request, Response, Transport};
+#[cfg(feature = "async")]
+use super::{AsyncTransport, BoxFuture};
+use crate::error::{Result, TransportError, TransportErrorKind};
+
+/// A pure-Rust `Transport` backed by reqwest and rustls, no libcurl or OpenSSL needed.
+pub struct ReqwestTransport {
+    options: HttpOptions,
+    // Built on first use: reqwest's blocking client can't be created from within an async runtime.
+    client: Mutex<Option<Client>>,
+}
+
+impl ReqwestTransport {
+    pub fn new() -> Self {
+        Self {
+            options: HttpOptions::new(),
+            client: Mutex::new(None),
+        }
+    }
+
+    /// Fails if the proxy url or the CA bundle is invalid.
+    pub fn with_options(options: &HttpOptions) -> Result<Self> {
+        let _ = builder(options)?;
+
+        Ok(Self {
+            options: options.clone(),
+            client: Mutex::new(None),
+        })
+    }
+
+    /// Uses a preconfigured reqwest client, e.g. with custom root certificates.
+    pub fn with_client(client: Client) -> Self {
+        Self {
+            options: HttpOptions::new(),
+            client: Mutex::new(Some(client)),
+        }
+    }
+
+    fn client(&self) -> Result<Client> {
+        let mut client = self.client.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(client) = client.as_ref() {
+            return Ok(client.clone());
+        }
+
+        let mut builder = ::reqwest::blocking::ClientBuilder::from(builder(&self.options)?);
+        // The blocking client reads bodies outside of reqwest's runtime, where a read timeout can't
+        // be enforced, so it bounds the whole exchange instead.
+        if let Some(timeout) = self.options.read_timeout {
+            builder = builder.timeout(timeout);
+        }
+        let built = builder.build().map_err(TransportError::from)?;
+        *client = Some(built.clone());
+
+        Ok(built)
+    }
+}
+
+impl Default for ReqwestTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, request: &Request) -> Result<Response> {
+        let mut builder = self.client()?.request(method(request.method), &request.url);
+        for (name, value) in &request.headers {
+            builder = builder.header(name.as_str(), value.as_str());
+        }
+        if let Some(body) = &request.body {
+            builder = builder.body(body.clone());
+        }
+        if let Some(timeout) = request.timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        let response = builder.send().map_err(TransportError::from)?;
+        let status = u32::from(response.status().as_u16());
+        let headers = headers(response.headers());
+        let body = response.bytes().map_err(TransportError::from)?.to_vec();
+
+        Ok(Response { status, headers, body })
+    }
+}
+
+/// An `AsyncTransport` backed by reqwest's async client and rustls.
+#[cfg(feature = "async")]
+pub struct AsyncReqwestTransport {
+    client: ::reqwest::Client,
+}
+
+#[cfg(feature = "async")]
+impl AsyncReqwestTransport {
+    pub fn new() -> Self {
+        Self::with_client(::reqwest::Client::new())
+    }
+
+    /// Fails if the proxy url or the CA bundle is invalid.
+    pub fn with_options(options: &HttpOptions) -> Result<Self> {
+        let mut builder = builder(options)?;
+        if let Some(timeout) = options.read_timeout {
+            builder = builder.read_timeout(timeout);
+        }
+        let client = builder.build().map_err(TransportError::from)?;
+
+        Ok(Self::with_client(client))
+    }
+
+    /// Uses a preconfigured reqwest client, e.g. with custom root certificates.
+    pub fn with_client(client: ::reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[cfg(feature = "async")]
+impl Default for AsyncReqwestTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncTransport for AsyncReqwestTransport {
+    fn send<'a>(&'a self, request: &'a Request) -> BoxFuture<'a, Result<Response>> {
+        Box::pin(async move {
+            let mut builder = self.client.request(method(request.method), &request.url);
+            for (name, value) in &request.headers {
+                builder = builder.header(name.as_str(), value.as_str());
+            }
+            if let Some(body) = &request.body {
+                builder = builder.body(body.clone());
+            }
+            if let Some(timeout) = request.timeout {
+                builder = builder.timeout(timeout);
+            }
+
+            let response = builder.send().await.map_err(TransportError::from)?;
+            let status = u32::from(response.status().as_u16());
+            let headers = headers(response.headers());
+            let body = response.bytes().await.map_err(TransportError::from)?.to_vec();
+
+            Ok(Response { status, headers, body })
+        })
+    }
+}
+
+/// An async client builder, which the blocking one wraps, configured with `options` but the read timeout.
+fn builder(options: &HttpOptions) -> Result<ClientBuilder> {
+    let mut builder = ClientBuilder::new();
+    if let Some(proxy) = &options.proxy {
+        builder = builder.proxy(Proxy::all(proxy).map_err(TransportError::from)?);
+    }
+    if let Some(timeout) = options.connect_timeout {
+        builder = builder.connect_timeout(timeout);
+    }
+    if let Some(path) = &options.ca_bundle {
+        let pem = std::fs::read(path).map_err(|e| TransportError::new(TransportErrorKind::Other, e))?;
+        for certificate in Certificate::from_pem_bundle(&pem).map_err(TransportError::from)? {
+            builder = builder.add_root_certificate(certificate);
+        }
+    }
+
+    Ok(builder)
+}
+
+fn method(method: Method) -> ::reqwest::Method {
+    match method {
+        Method::Get => ::reqwest::Method::GET,
+        Method::Post => ::reqwest::Method::POST,
+        Method::Put => ::reqwest::Method::PUT,
+        Method::Patch => ::reqwest::Method::PATCH,
+        Method::Delete => ::reqwest::Method::DELETE,
+    }
+}
+
+fn headers(headers: &::reqwest::header::HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .map(|(name, value)| (name.to_string(), String::from_utf8_lossy(value.as_bytes()).into_owned()))
+        .collect()
+}
+
+impl From<::reqwest::Error> for TransportError {
+    fn from(e: ::reqwest::Error) -> Self {
+        let kind = if e.is_timeout() {
+            TransportErrorKind::Timeout
+        } else if e.is_connect() || e.is_body() {
+            TransportErrorKind::Connect
+        } else {
+            TransportErrorKind::Other
+        };
+
+        // The url carries the API key, if any.
+        Self::new(kind, e.without_url())
+    }
+}